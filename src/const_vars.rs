@@ -1,7 +1,3 @@
-///
-/// 常量模块
-///
-
 ///本地文件缓存目录
 pub const CACHE_TEMP_HOME: &str = "CACHE_TEMP_HOME";
 
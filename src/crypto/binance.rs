@@ -0,0 +1,144 @@
+use polars::prelude::{DataFrame, NamedFrom, Series};
+use reqwest::{Method, Request, Url};
+use serde_json::Value;
+
+use super::exchange::{CryptoExchange, KlineInterval};
+use crate::utils::JsonUtils;
+
+///
+/// 币安现货 REST 接口
+/// https://binance-docs.github.io/apidocs/spot/cn/
+///
+#[derive(Clone, Debug)]
+pub struct BinanceExchange {
+    /// 接口地址，默认 https://api.binance.com
+    pub base_url: String,
+}
+
+impl Default for BinanceExchange {
+    fn default() -> Self {
+        BinanceExchange {
+            base_url: "https://api.binance.com".to_string(),
+        }
+    }
+}
+
+impl BinanceExchange {
+    fn interval(interval: KlineInterval) -> &'static str {
+        match interval {
+            KlineInterval::Minute1 => "1m",
+            KlineInterval::Minute5 => "5m",
+            KlineInterval::Minute15 => "15m",
+            KlineInterval::Hour1 => "1h",
+            KlineInterval::Hour4 => "4h",
+            KlineInterval::Day1 => "1d",
+            KlineInterval::Week1 => "1w",
+        }
+    }
+}
+
+impl CryptoExchange for BinanceExchange {
+    fn name(&self) -> &str {
+        "binance"
+    }
+
+    fn ticker_request(&self) -> Request {
+        let url = Url::parse(&format!("{}/api/v3/ticker/24hr", self.base_url)).unwrap();
+
+        Request::new(Method::GET, url)
+    }
+
+    fn kline_request(
+        &self,
+        symbol: &str,
+        interval: KlineInterval,
+        start: i64,
+        end: i64,
+    ) -> Request {
+        let url = Url::parse_with_params(
+            &format!("{}/api/v3/klines", self.base_url),
+            &[
+                ("symbol", symbol.to_uppercase().as_str()),
+                ("interval", BinanceExchange::interval(interval)),
+                ("startTime", &start.to_string()),
+                ("endTime", &end.to_string()),
+                ("limit", &self.kline_limit().to_string()),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+
+    fn kline_limit(&self) -> usize {
+        1000
+    }
+
+    ///
+    /// [{"symbol":"BTCUSDT","lastPrice":"34500.01",...}, ...]，价格、数量均为字符串
+    ///
+    fn parse_tickers(&self, body: &str) -> anyhow::Result<DataFrame> {
+        let json: Value = serde_json::from_str(body)?;
+        let rows = json.as_array().cloned().unwrap_or_default();
+        if rows.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+
+        let symbols: Vec<Option<&str>> = rows
+            .iter()
+            .map(|row| row.get("symbol").and_then(|s| s.as_str()))
+            .collect();
+        let mut columns = vec![Series::new("symbol", symbols)];
+        for (name, key) in [
+            ("last", "lastPrice"),
+            ("open", "openPrice"),
+            ("high", "highPrice"),
+            ("low", "lowPrice"),
+            ("change", "priceChange"),
+            ("change_pct", "priceChangePercent"),
+            ("volume", "volume"),
+            ("quote_volume", "quoteVolume"),
+        ] {
+            let values: Vec<Option<f64>> = rows
+                .iter()
+                .map(|row| JsonUtils::number(row.get(key)))
+                .collect();
+            columns.push(Series::new(name, values));
+        }
+
+        Ok(DataFrame::new(columns)?)
+    }
+
+    ///
+    /// [[开盘时间,开盘,最高,最低,收盘,成交量,收盘时间,成交额,成交笔数,...], ...]
+    ///
+    fn parse_klines(&self, body: &str) -> anyhow::Result<DataFrame> {
+        let json: Value = serde_json::from_str(body)?;
+        let rows = json.as_array().cloned().unwrap_or_default();
+        if rows.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+
+        let open_times: Vec<Option<i64>> = rows
+            .iter()
+            .map(|row| row.get(0).and_then(|t| t.as_i64()))
+            .collect();
+        let mut columns = vec![Series::new("open_time", open_times)];
+        for (name, index) in [
+            ("open", 1),
+            ("high", 2),
+            ("low", 3),
+            ("close", 4),
+            ("volume", 5),
+            ("quote_volume", 7),
+        ] {
+            let values: Vec<Option<f64>> = rows
+                .iter()
+                .map(|row| JsonUtils::number(row.get(index)))
+                .collect();
+            columns.push(Series::new(name, values));
+        }
+
+        Ok(DataFrame::new(columns)?)
+    }
+}
@@ -0,0 +1,257 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc},
+    prelude::{DataFrame, DataType, NamedFrom, Schema, Series},
+};
+use reqwest::Request;
+
+use crate::{
+    utils::HttpClient, DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// K线周期
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KlineInterval {
+    Minute1,
+    Minute5,
+    Minute15,
+    Hour1,
+    Hour4,
+    Day1,
+    Week1,
+}
+
+impl KlineInterval {
+    ///
+    /// 周期毫秒数
+    ///
+    pub fn millis(&self) -> i64 {
+        let minutes = match self {
+            KlineInterval::Minute1 => 1,
+            KlineInterval::Minute5 => 5,
+            KlineInterval::Minute15 => 15,
+            KlineInterval::Hour1 => 60,
+            KlineInterval::Hour4 => 240,
+            KlineInterval::Day1 => 1440,
+            KlineInterval::Week1 => 10080,
+        };
+        minutes * 60 * 1000
+    }
+}
+
+///
+/// 数字货币交易所适配器，负责构造请求及将响应解析为统一列名的 DataFrame
+///
+/// 现货行情列: symbol,last,open,high,low,change,change_pct,volume,quote_volume
+/// K线列: open_time(毫秒时间戳, Int64),open,high,low,close,volume,quote_volume
+///
+pub trait CryptoExchange: Clone + Send + Sync {
+    ///
+    /// 交易所名称
+    ///
+    fn name(&self) -> &str;
+
+    ///
+    /// 全部交易对24小时行情请求
+    ///
+    fn ticker_request(&self) -> Request;
+
+    ///
+    /// K线请求，start、end 为毫秒时间戳(含)，单次最多返回 kline_limit 条
+    ///
+    fn kline_request(&self, symbol: &str, interval: KlineInterval, start: i64, end: i64)
+        -> Request;
+
+    ///
+    /// 单次K线请求的最大条数
+    ///
+    fn kline_limit(&self) -> usize;
+
+    ///
+    /// 解析现货行情
+    ///
+    fn parse_tickers(&self, body: &str) -> anyhow::Result<DataFrame>;
+
+    ///
+    /// 解析K线
+    ///
+    fn parse_klines(&self, body: &str) -> anyhow::Result<DataFrame>;
+}
+
+///
+/// 数字货币现货24小时行情，数据来源由交易所适配器决定
+///
+#[derive(Clone, Debug)]
+pub struct CryptoTickerDataSource<E: CryptoExchange> {
+    pub exchange: E,
+}
+
+impl<E: CryptoExchange> HttpSource for CryptoTickerDataSource<E> {
+    fn request(&self) -> Request {
+        self.exchange.ticker_request()
+    }
+}
+
+impl<E: CryptoExchange> DataResultFormat for CryptoTickerDataSource<E> {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = self.exchange.parse_tickers(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("symbol", "代码"),
+            ("last", "最新价"),
+            ("open", "开盘"),
+            ("high", "最高"),
+            ("low", "最低"),
+            ("change", "涨跌额"),
+            ("change_pct", "涨跌幅"),
+            ("volume", "成交量"),
+            ("quote_volume", "成交额"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "代码" => DataType::Utf8,
+                _ => DataType::Float64,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl<E: CryptoExchange + 'static> RealTimeData for CryptoTickerDataSource<E> {
+    ///
+    /// 交易所全部交易对24小时行情
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+///
+/// 数字货币K线，数据来源由交易所适配器决定，时间为 UTC
+///
+#[derive(Clone, Debug)]
+pub struct CryptoKlineDataSource<E: CryptoExchange> {
+    pub exchange: E,
+    pub interval: KlineInterval,
+}
+
+impl<E: CryptoExchange> DataResultFormat for CryptoKlineDataSource<E> {
+    ///
+    /// 由 open_time 生成 UTC 时间字符串
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let mut df = self.exchange.parse_klines(&body)?;
+            if df.height() > 0 {
+                let times: Vec<Option<String>> = df
+                    .column("open_time")?
+                    .cast(&DataType::Int64)?
+                    .i64()?
+                    .into_iter()
+                    .map(|t| {
+                        DateTime::from_timestamp_millis(t?)
+                            .map(|t| t.naive_utc().format("%Y-%m-%d %H:%M:%S").to_string())
+                    })
+                    .collect();
+                df.with_column(Series::new("time", times))?;
+            }
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("time", "时间"),
+            ("open", "开盘"),
+            ("high", "最高"),
+            ("low", "最低"),
+            ("close", "收盘"),
+            ("volume", "成交量"),
+            ("quote_volume", "成交额"),
+            ("open_time", "开盘时间戳"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("时间".to_string(), DataType::Utf8);
+        schema.with_column("开盘".to_string(), DataType::Float64);
+        schema.with_column("最高".to_string(), DataType::Float64);
+        schema.with_column("最低".to_string(), DataType::Float64);
+        schema.with_column("收盘".to_string(), DataType::Float64);
+        schema.with_column("成交量".to_string(), DataType::Float64);
+        schema.with_column("成交额".to_string(), DataType::Float64);
+        schema.with_column("开盘时间戳".to_string(), DataType::Int64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl<E: CryptoExchange + 'static> HistoryData for CryptoKlineDataSource<E> {
+    ///
+    /// [start, end] 期间(UTC)的K线，market 不区分传空即可，symbol 为交易对，如 BTCUSDT
+    ///
+    /// 按 kline_limit 分页获取，截止 UTC 今日之前的分页永久缓存，含今日的分页K线未收盘不缓存
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let millis = |date: NaiveDate| date.and_time(NaiveTime::MIN).and_utc().timestamp_millis();
+        let end_ms = millis(end + Duration::days(1)) - 1;
+        let today_ms = millis(Utc::now().date_naive());
+        let limit = self.exchange.kline_limit();
+
+        let mut data_frame = self.col_schema().as_ref().map(DataFrame::from).unwrap();
+        let mut from = millis(start);
+        while from <= end_ms {
+            let to = end_ms.min(from + self.interval.millis() * limit as i64 - 1);
+            let request = self.exchange.kline_request(symbol, self.interval, from, to);
+            let result = if to < today_ms {
+                HttpClient::exec_by_permanent_cache(request, self.clone()).await?
+            } else {
+                HttpClient::exec_and_format(request, self.clone()).await?
+            };
+
+            // 上市前的区间返回空
+            if let Some(df) = result.data.filter(|df| df.height() > 0) {
+                data_frame.vstack_mut(&df)?;
+            }
+            from = to + 1;
+        }
+
+        Ok(DataResult::new(symbol.to_string(), data_frame))
+    }
+}
@@ -0,0 +1,5 @@
+/// 币安现货 REST 适配器
+pub mod binance;
+
+/// 交易所适配器、现货行情及K线数据源
+pub mod exchange;
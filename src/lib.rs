@@ -1,4 +1,4 @@
-use crate::utils::{DateUtils, Envs};
+use crate::utils::{DateUtils, Envs, HttpClient};
 
 use async_trait::async_trait;
 use core::convert::From;
@@ -6,7 +6,10 @@ use polars::export::chrono::NaiveDate;
 use polars::frame::row::Row;
 use polars::frame::DataFrame;
 use polars::io::{SerReader, SerWriter};
-use polars::prelude::{CsvReader, CsvWriter, JsonFormat, JsonReader, Schema};
+use polars::lazy::dsl::{col, lit, Expr};
+use polars::prelude::{
+    CsvReader, CsvWriter, DataType, IntoLazy, JsonFormat, JsonReader, Schema, NULL,
+};
 use reqwest::Request;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
@@ -15,7 +18,11 @@ use std::path::Path;
 use utils::IoUtils;
 
 pub mod cffex;
+///
+/// 常量模块
+///
 pub mod const_vars;
+pub mod crypto;
 pub mod sina;
 pub mod utils;
 
@@ -95,10 +102,71 @@ pub trait DataResultFormat {
     ///
     fn col_alias(&self) -> Option<Vec<(&str, &str)>>;
 
+    ///
+    /// 列类型(按重命名后的列名)，格式化时按此转换列类型，同时作为加载缓存时的schema信息
+    ///
+    fn col_schema(&self) -> Option<Schema> {
+        None
+    }
+
     ///
     /// 格式化程序，包括列名重命名及加载缓存时schema信息
     ///
-    fn format(&self, data_result_format: Option<DataFrame>) -> DataResult<DataFrame>;
+    /// 默认按 col_alias 的顺序选取并重命名列，缺失的列补空值，再按 col_schema 转换列类型
+    ///
+    fn format(&self, data_result_format: Option<DataFrame>) -> DataResult<DataFrame> {
+        if let Some(result) = data_result_format {
+            let schema = self.col_schema();
+
+            // 无数据时按schema返回空表，保证列与缓存一致
+            if result.height() == 0 {
+                let df = schema
+                    .as_ref()
+                    .map(DataFrame::from)
+                    .unwrap_or_else(DataFrame::empty);
+                return DataResult {
+                    data_id: None,
+                    data: Some(df),
+                };
+            }
+
+            let Some(col_alias) = self.col_alias() else {
+                return DataResult {
+                    data_id: None,
+                    data: Some(result),
+                };
+            };
+
+            let names = result.get_column_names();
+            let mut col_alias_exprs: Vec<Expr> = vec![];
+            for (c, a) in col_alias {
+                let expr = if names.contains(&c) {
+                    col(c)
+                } else {
+                    lit(NULL)
+                };
+                let data_type = schema
+                    .as_ref()
+                    .and_then(|s| s.get(a).cloned())
+                    .unwrap_or(DataType::Utf8);
+                col_alias_exprs.push(expr.cast(data_type).alias(a));
+            }
+
+            match result.lazy().select(col_alias_exprs).collect() {
+                Ok(df) => {
+                    return DataResult {
+                        data_id: None,
+                        data: Some(df),
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("data frame 格式化失败:{}", e);
+                }
+            }
+        }
+
+        DataResult::default()
+    }
 }
 
 ///
@@ -111,9 +179,11 @@ pub trait HttpSource {
     fn request(&self) -> Request;
 
     ///
-    /// id 生产策略
+    /// id 生产策略, 默认为请求url的md5
     ///
-    fn id(&self) -> String;
+    fn id(&self) -> String {
+        HttpClient::request_id(&self.request())
+    }
 }
 
 ///
@@ -146,21 +216,7 @@ impl ResultCached<DataFrame> for DataResult<DataFrame> {
             None => {
                 tracing::warn!("data_id 为空，缓存文件失败");
             }
-            Some(id) => {
-                let cache_file = DataResult::cache_file_name(id);
-                let file_result = File::create(&cache_file);
-                match file_result {
-                    Ok(mut file) => {
-                        CsvWriter::new(&mut file)
-                            .has_header(true)
-                            .finish(&mut self.data.clone().unwrap())
-                            .expect("缓存数据存储失败");
-                    }
-                    Err(e) => {
-                        tracing::warn!("{}缓存文件创建失败{}", &cache_file, e);
-                    }
-                }
-            }
+            Some(id) => self.cache_to(&DataResult::cache_file_name(id)),
         }
     }
 
@@ -170,40 +226,7 @@ impl ResultCached<DataFrame> for DataResult<DataFrame> {
                 tracing::warn!("data_id 为空，加载缓存文件失败");
                 Ok(DataResult::empty())
             }
-            Some(id) => {
-                let cache_file = DataResult::cache_file_name(id);
-                tracing::debug!("load file path:{:?}", &cache_file);
-
-                let data_frame_result = CsvReader::from_path(&cache_file);
-
-                match data_frame_result {
-                    Ok(csv_file) => {
-                        if let Some(schema) = schema_opt {
-                            let data_frame = csv_file
-                                .has_header(true)
-                                .with_schema(&schema)
-                                .with_parse_dates(true)
-                                .finish()
-                                .expect("TODO: panic message");
-
-                            return Ok(DataResult {
-                                data_id: Some(id.clone()),
-                                data: Some(data_frame.clone()),
-                            });
-                        } else {
-                            tracing::warn!(
-                                "load的schema为None,缓存数据可能加载错误或为空,请提供！！！"
-                            )
-                        }
-
-                        Ok(DataResult::empty())
-                    }
-                    Err(e) => {
-                        tracing::warn!("加载缓存文件{}, 解析失败:{}", &cache_file, e);
-                        Ok(DataResult::empty())
-                    }
-                }
-            }
+            Some(id) => DataResult::load_from(id, &DataResult::cache_file_name(id), schema_opt),
         }
     }
 }
@@ -222,22 +245,120 @@ impl DataResult<DataFrame> {
         }
     }
 
-    fn cache_file_name(data_id: &String) -> String {
-        let cache_temp_home = Envs::cache_temp_home();
-        let path = Path::new(&cache_temp_home);
-        if !&path.try_exists().ok().unwrap() && IoUtils::create_dir_recursive(path).is_err() {
-            tracing::warn!("{} 缓存目录创建失败", &cache_temp_home);
+    ///
+    /// 判断永久缓存是否存在，永久缓存不按日期失效，用于已定稿的历史数据
+    ///
+    pub fn is_cached_permanently(&self) -> bool {
+        match &self.data_id {
+            None => false,
+            Some(id) => Path::new(&DataResult::permanent_cache_file_name(id)).exists(),
+        }
+    }
+
+    ///
+    /// 永久缓存数据
+    ///
+    pub fn cache_permanently(&self) {
+        match &self.data_id {
+            None => {
+                tracing::warn!("data_id 为空，缓存文件失败");
+            }
+            Some(id) => self.cache_to(&DataResult::permanent_cache_file_name(id)),
         }
+    }
+
+    ///
+    /// 加载永久缓存
+    ///
+    pub fn load_permanently(
+        &self,
+        schema_opt: Option<Schema>,
+    ) -> Result<DataResult<DataFrame>, anyhow::Error> {
+        match &self.data_id {
+            None => {
+                tracing::warn!("data_id 为空，加载缓存文件失败");
+                Ok(DataResult::empty())
+            }
+            Some(id) => {
+                DataResult::load_from(id, &DataResult::permanent_cache_file_name(id), schema_opt)
+            }
+        }
+    }
+
+    fn cache_to(&self, cache_file: &str) {
+        let file_result = File::create(cache_file);
+        match file_result {
+            Ok(mut file) => {
+                CsvWriter::new(&mut file)
+                    .has_header(true)
+                    .finish(&mut self.data.clone().unwrap())
+                    .expect("缓存数据存储失败");
+            }
+            Err(e) => {
+                tracing::warn!("{}缓存文件创建失败{}", cache_file, e);
+            }
+        }
+    }
+
+    fn load_from(
+        id: &str,
+        cache_file: &str,
+        schema_opt: Option<Schema>,
+    ) -> Result<DataResult<DataFrame>, anyhow::Error> {
+        tracing::debug!("load file path:{:?}", cache_file);
 
+        let data_frame_result = CsvReader::from_path(cache_file);
+
+        match data_frame_result {
+            Ok(csv_file) => {
+                if let Some(schema) = schema_opt {
+                    let data_frame = csv_file
+                        .has_header(true)
+                        .with_schema(&schema)
+                        .with_parse_dates(true)
+                        .finish()
+                        .expect("TODO: panic message");
+
+                    return Ok(DataResult {
+                        data_id: Some(id.to_string()),
+                        data: Some(data_frame.clone()),
+                    });
+                } else {
+                    tracing::warn!("load的schema为None,缓存数据可能加载错误或为空,请提供！！！")
+                }
+
+                Ok(DataResult::empty())
+            }
+            Err(e) => {
+                tracing::warn!("加载缓存文件{}, 解析失败:{}", cache_file, e);
+                Ok(DataResult::empty())
+            }
+        }
+    }
+
+    fn cache_file_name(data_id: &String) -> String {
         format_args!(
-            "{}/{}-{}{}",
-            cache_temp_home,
-            &data_id,
+            "{}-{}{}",
+            DataResult::cache_file_prefix(data_id),
             utils::DateUtils::now_fmt_ymd(),
             ".csv"
         )
         .to_string()
     }
+
+    fn permanent_cache_file_name(data_id: &String) -> String {
+        format!("{}.csv", DataResult::cache_file_prefix(data_id))
+    }
+
+    fn cache_file_prefix(data_id: &String) -> String {
+        let cache_temp_home = Envs::cache_temp_home();
+        let path = Path::new(&cache_temp_home);
+        if !&path.try_exists().ok().unwrap() && IoUtils::create_dir_recursive(path).is_err() {
+            tracing::warn!("{} 缓存目录创建失败", &cache_temp_home);
+        }
+
+        format!("{}/{}", cache_temp_home, data_id)
+    }
 }
 
 impl From<Row<'_>> for StockData {
@@ -0,0 +1,252 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Local, NaiveDate},
+    lazy::dsl::col,
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::eastmoney::{EastmoneyClist, EastmoneyDataCenter, EastmoneyKline},
+    utils::HttpClient,
+    DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 东方财富网-可转债比价表-实时行情，并关联可转债基本信息中的信用评级、到期日期，计算剩余年限
+/// https://quote.eastmoney.com/center/fullscreenlist.html#convertible_comparison
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyConvertibleBondSpotDataSource {}
+
+impl HttpSource for EastmoneyConvertibleBondSpotDataSource {
+    fn request(&self) -> Request {
+        EastmoneyClist::request(
+            "b:MK0354",
+            "f2,f3,f5,f6,f12,f14,f26,f227,f229,f230,f232,f234,f235,f236,f237,f238,f239,f240,f241,f242,f243",
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyConvertibleBondSpotDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyClist::to_dataframe(&body)?;
+            return Ok(DataResult::<DataFrame>::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("f12", "代码"),
+            ("f14", "名称"),
+            ("f2", "最新价"),
+            ("f3", "涨跌幅"),
+            ("f5", "成交量"),
+            ("f6", "成交额"),
+            ("f232", "正股代码"),
+            ("f234", "正股名称"),
+            ("f229", "正股最新价"),
+            ("f230", "正股涨跌幅"),
+            ("f235", "转股价"),
+            ("f236", "转股价值"),
+            ("f237", "转股溢价率"),
+            ("f227", "纯债价值"),
+            ("f238", "纯债溢价率"),
+            ("f239", "回售触发价"),
+            ("f240", "强赎触发价"),
+            ("f241", "到期赎回价"),
+            ("f242", "开始转股日"),
+            ("f26", "上市日期"),
+            ("f243", "申购日期"),
+            ("f12", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("最新价".to_string(), DataType::Float64);
+        schema.with_column("涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("成交量".to_string(), DataType::Float64);
+        schema.with_column("成交额".to_string(), DataType::Float64);
+        schema.with_column("正股代码".to_string(), DataType::Utf8);
+        schema.with_column("正股名称".to_string(), DataType::Utf8);
+        schema.with_column("正股最新价".to_string(), DataType::Float64);
+        schema.with_column("正股涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("转股价".to_string(), DataType::Float64);
+        schema.with_column("转股价值".to_string(), DataType::Float64);
+        schema.with_column("转股溢价率".to_string(), DataType::Float64);
+        schema.with_column("纯债价值".to_string(), DataType::Float64);
+        schema.with_column("纯债溢价率".to_string(), DataType::Float64);
+        schema.with_column("回售触发价".to_string(), DataType::Float64);
+        schema.with_column("强赎触发价".to_string(), DataType::Float64);
+        schema.with_column("到期赎回价".to_string(), DataType::Float64);
+        schema.with_column("开始转股日".to_string(), DataType::Utf8);
+        schema.with_column("上市日期".to_string(), DataType::Utf8);
+        schema.with_column("申购日期".to_string(), DataType::Utf8);
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyConvertibleBondSpotDataSource {
+    ///
+    /// 所有上市可转债的实时行情
+    /// :return: 比价表各列及 信用评级,到期日期,剩余年限
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let mut result = HttpClient::exec_by_cache(self.request(), self.clone()).await?;
+
+        let info = EastmoneyConvertibleBondInfoDataSource {}
+            .real_time_data()
+            .await?;
+
+        if let (Some(spot), Some(info)) = (&result.data, info.data) {
+            if spot.height() > 0 && info.height() > 0 {
+                let df = spot
+                    .clone()
+                    .lazy()
+                    .left_join(
+                        info.lazy()
+                            .select([col("代码"), col("信用评级"), col("到期日期")]),
+                        col("代码"),
+                        col("代码"),
+                    )
+                    .collect()?;
+
+                let remaining = remaining_years(&df, Local::now().date_naive())?;
+                result.data = Some(df.hstack(&[remaining])?);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyConvertibleBondSpotDataSource {
+    ///
+    /// 可转债日K线，market 为 sh 或 sz，symbol 为转债代码，如 113050
+    ///
+    async fn history_daily(
+        self,
+        market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let secid = EastmoneyClist::secid(&format!("{}{}", market, symbol));
+
+        HttpClient::exec_by_cache(
+            EastmoneyKline::request(&secid, "0", start, end),
+            EastmoneyKline,
+        )
+        .await
+    }
+}
+
+///
+/// 按到期日期计算剩余年限
+///
+fn remaining_years(df: &DataFrame, today: NaiveDate) -> anyhow::Result<Series> {
+    let years: Vec<Option<f64>> = df
+        .column("到期日期")?
+        .utf8()?
+        .into_iter()
+        .map(|date| {
+            date.and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+                .map(|d| (d - today).num_days() as f64 / 365.0)
+        })
+        .collect();
+
+    Ok(Series::new("剩余年限", years))
+}
+
+///
+/// 东方财富网-数据中心-可转债基本信息，包括信用评级、发行规模、到期日期等
+/// https://data.eastmoney.com/kzz/default.html
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyConvertibleBondInfoDataSource {}
+
+impl HttpSource for EastmoneyConvertibleBondInfoDataSource {
+    fn request(&self) -> Request {
+        EastmoneyDataCenter::request(
+            "RPT_BOND_CB_LIST",
+            "",
+            "PUBLIC_START_DATE,SECURITY_CODE",
+            "-1,-1",
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyConvertibleBondInfoDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::<DataFrame>::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("SECURITY_CODE", "代码"),
+            ("SECURITY_NAME_ABBR", "名称"),
+            ("CONVERT_STOCK_CODE", "正股代码"),
+            ("RATING", "信用评级"),
+            ("ACTUAL_ISSUE_SCALE", "发行规模"),
+            ("BOND_EXPIRE", "发行年限"),
+            ("INITIAL_TRANSFER_PRICE", "初始转股价"),
+            ("PUBLIC_START_DATE", "申购日期"),
+            ("LISTING_DATE", "上市日期"),
+            ("EXPIRE_DATE", "到期日期"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("正股代码".to_string(), DataType::Utf8);
+        schema.with_column("信用评级".to_string(), DataType::Utf8);
+        schema.with_column("发行规模".to_string(), DataType::Float64);
+        schema.with_column("发行年限".to_string(), DataType::Float64);
+        schema.with_column("初始转股价".to_string(), DataType::Float64);
+        schema.with_column("申购日期".to_string(), DataType::Utf8);
+        schema.with_column("上市日期".to_string(), DataType::Utf8);
+        schema.with_column("到期日期".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyConvertibleBondInfoDataSource {
+    ///
+    /// 所有可转债的基本信息
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
@@ -0,0 +1,5 @@
+/// 可转债实时行情、历史行情及基本信息
+pub mod convertible;
+
+/// 国债收益率曲线及债券指数
+pub mod treasury;
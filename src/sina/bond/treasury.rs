@@ -0,0 +1,237 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Duration, Local, NaiveDate},
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::{Method, Request, Url};
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyKline,
+    utils::{HtmlUtils, HttpClient},
+    DataResult, DataResultFormat, HistoryData, HttpSource,
+};
+
+///
+/// 收益率曲线期限
+///
+const TENORS: [&str; 8] = ["3月", "6月", "1年", "3年", "5年", "7年", "10年", "30年"];
+
+///
+/// 中国债券信息网-中债国债收益率曲线(到期收益率)历史数据
+/// https://yield.chinabond.com.cn/cbweb-pbc-web/pbc/historyQuery
+///
+/// 期限为 3月、6月、1年、3年、5年、7年、10年、30年，收益率单位为 %
+///
+#[derive(Clone, Debug)]
+pub struct ChinaBondYieldCurveDataSource {}
+
+impl ChinaBondYieldCurveDataSource {
+    /// 接口单次查询跨度不超过一年
+    const MAX_DAYS: i64 = 365;
+
+    fn request_by_date(start: NaiveDate, end: NaiveDate) -> Request {
+        let url = Url::parse_with_params(
+            "https://yield.chinabond.com.cn/cbweb-pbc-web/pbc/historyQuery",
+            &[
+                ("startDate", start.format("%Y-%m-%d").to_string().as_str()),
+                ("endDate", &end.format("%Y-%m-%d").to_string()),
+                ("gjqx", "0"),
+                ("qxId", "ycqx"),
+                ("locale", "cn_ZH"),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+
+    ///
+    /// 解析 html 表格，仅保留国债收益率曲线行: 曲线名称,日期,3月,...,30年
+    ///
+    fn parse_table(body: &str) -> Vec<Vec<String>> {
+        body.split("<tr")
+            .skip(1)
+            .map(|tr| {
+                tr.split("<td")
+                    .skip(1)
+                    .map(|td| {
+                        let td = td.split("</td>").next().unwrap_or_default();
+                        HtmlUtils::strip_tags(&format!("<{}", td))
+                    })
+                    .collect::<Vec<String>>()
+            })
+            .filter(|cells| {
+                cells.len() >= TENORS.len() + 2 && cells[0].contains("国债") && cells[1].len() == 10
+            })
+            .collect()
+    }
+
+    ///
+    /// 期限利差，单位为基点(bp)，long、short 为期限列名，如 10年、1年
+    /// :return: 日期,期限利差
+    ///
+    pub fn term_spread(curve: &DataFrame, long: &str, short: &str) -> anyhow::Result<DataFrame> {
+        let df = curve
+            .clone()
+            .lazy()
+            .select([
+                col("日期"),
+                ((col(long) - col(short)) * lit(100.0)).alias("期限利差"),
+            ])
+            .collect()?;
+
+        Ok(df)
+    }
+}
+
+impl HttpSource for ChinaBondYieldCurveDataSource {
+    fn request(&self) -> Request {
+        let today = Local::now().date_naive();
+        ChinaBondYieldCurveDataSource::request_by_date(today - Duration::days(30), today)
+    }
+}
+
+impl DataResultFormat for ChinaBondYieldCurveDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let rows = ChinaBondYieldCurveDataSource::parse_table(&body);
+            if rows.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let mut columns = vec![Series::new(
+                "日期",
+                rows.iter().map(|r| r[1].as_str()).collect::<Vec<_>>(),
+            )];
+            for (i, tenor) in TENORS.iter().enumerate() {
+                let values: Vec<Option<f64>> = rows.iter().map(|r| r[i + 2].parse().ok()).collect();
+                columns.push(Series::new(tenor, values));
+            }
+
+            return Ok(DataResult::new("".to_string(), DataFrame::new(columns)?));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let mut ca = vec![("日期", "日期")];
+        ca.extend(TENORS.iter().map(|t| (*t, *t)));
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        for tenor in TENORS {
+            schema.with_column(tenor.to_string(), DataType::Float64);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for ChinaBondYieldCurveDataSource {
+    ///
+    /// [start, end] 期间中债国债收益率曲线，market、symbol 不区分传空即可，按日期倒序
+    ///
+    /// 按年拆分查询，今日之前的区间永久缓存
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        _symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let today = Local::now().date_naive();
+        let mut data_frame = self.col_schema().as_ref().map(DataFrame::from).unwrap();
+
+        let mut from = start;
+        while from <= end {
+            let to = end.min(from + Duration::days(ChinaBondYieldCurveDataSource::MAX_DAYS - 1));
+            let request = ChinaBondYieldCurveDataSource::request_by_date(from, to);
+            let result = if to < today {
+                HttpClient::exec_by_permanent_cache(request, self.clone()).await?
+            } else {
+                HttpClient::exec_by_cache(request, self.clone()).await?
+            };
+            if let Some(df) = result.data {
+                data_frame.vstack_mut(&df)?;
+            }
+            from = to + Duration::days(1);
+        }
+
+        let df = data_frame
+            .lazy()
+            .sort_by_exprs([col("日期")], [true], false)
+            .collect()?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+}
+
+///
+/// 债券指数
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BondIndex {
+    /// 上证国债指数 000012
+    Treasury,
+    /// 上证企业债指数 000013
+    Enterprise,
+    /// 上证公司债指数 000923
+    Corporate,
+    /// 中证转债指数 000832
+    Convertible,
+    /// 中证全债指数 H11001
+    AllBond,
+}
+
+impl BondIndex {
+    ///
+    /// 东方财富证券id
+    ///
+    fn secid(&self) -> &str {
+        match self {
+            BondIndex::Treasury => "1.000012",
+            BondIndex::Enterprise => "1.000013",
+            BondIndex::Corporate => "1.000923",
+            BondIndex::Convertible => "1.000832",
+            BondIndex::AllBond => "2.H11001",
+        }
+    }
+}
+
+///
+/// 东方财富网-债券指数日K线
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyBondIndexDataSource {
+    pub index: BondIndex,
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyBondIndexDataSource {
+    ///
+    /// [start, end] 期间债券指数日行情，market、symbol 不区分传空即可
+    /// :return: 代码,名称,日期,开盘,收盘,最高,最低,成交量,成交额,振幅,涨跌幅,涨跌额,换手率
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        _symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(
+            EastmoneyKline::request(self.index.secid(), "0", start, end),
+            EastmoneyKline,
+        )
+        .await
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::prelude::{DataFrame, DataType, Schema};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyClist, utils::HttpClient, DataResult, DataResultFormat,
+    HttpSource, RealTimeData,
+};
+
+///
+/// 场内基金类型
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FundType {
+    /// 交易型开放式指数基金
+    Etf,
+    /// 上市型开放式基金
+    Lof,
+}
+
+impl FundType {
+    fn fs(&self) -> &str {
+        match self {
+            FundType::Etf => "b:MK0021,b:MK0022,b:MK0023,b:MK0024",
+            FundType::Lof => "b:MK0404,b:MK0405,b:MK0406,b:MK0407",
+        }
+    }
+}
+
+///
+/// 东方财富网-场内基金(ETF、LOF)-实时行情数据，包含 IOPV 实时估值及折溢价率
+/// https://quote.eastmoney.com/center/gridlist.html#fund_etf
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyFundSpotDataSource {
+    pub fund_type: FundType,
+}
+
+impl HttpSource for EastmoneyFundSpotDataSource {
+    fn request(&self) -> Request {
+        EastmoneyClist::request(
+            self.fund_type.fs(),
+            "f2,f3,f4,f5,f6,f7,f8,f12,f14,f15,f16,f17,f18,f20,f21,f38,f297,f402,f441",
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyFundSpotDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyClist::to_dataframe(&body)?;
+            return Ok(DataResult::<DataFrame>::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("f12", "代码"),
+            ("f14", "名称"),
+            ("f2", "最新价"),
+            ("f441", "IOPV实时估值"),
+            ("f402", "折价率"),
+            ("f3", "涨跌幅"),
+            ("f4", "涨跌额"),
+            ("f5", "成交量"),
+            ("f6", "成交额"),
+            ("f7", "振幅"),
+            ("f8", "换手率"),
+            ("f15", "最高"),
+            ("f16", "最低"),
+            ("f17", "今开"),
+            ("f18", "昨收"),
+            ("f38", "最新份额"),
+            ("f21", "流通市值"),
+            ("f20", "总市值"),
+            ("f297", "数据日期"),
+            ("f12", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("最新价".to_string(), DataType::Float64);
+        schema.with_column("IOPV实时估值".to_string(), DataType::Float64);
+        schema.with_column("折价率".to_string(), DataType::Float64);
+        schema.with_column("涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("涨跌额".to_string(), DataType::Float64);
+        schema.with_column("成交量".to_string(), DataType::Float64);
+        schema.with_column("成交额".to_string(), DataType::Float64);
+        schema.with_column("振幅".to_string(), DataType::Float64);
+        schema.with_column("换手率".to_string(), DataType::Float64);
+        schema.with_column("最高".to_string(), DataType::Float64);
+        schema.with_column("最低".to_string(), DataType::Float64);
+        schema.with_column("今开".to_string(), DataType::Float64);
+        schema.with_column("昨收".to_string(), DataType::Float64);
+        schema.with_column("最新份额".to_string(), DataType::Float64);
+        schema.with_column("流通市值".to_string(), DataType::Float64);
+        schema.with_column("总市值".to_string(), DataType::Float64);
+        schema.with_column("数据日期".to_string(), DataType::Utf8);
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyFundSpotDataSource {
+    ///
+    /// 所有 ETF 或 LOF 的实时行情，折价率为负表示折价
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
@@ -0,0 +1,5 @@
+/// 场内基金(ETF、LOF)实时行情
+pub mod etf;
+
+/// 开放式基金历史净值
+pub mod nav;
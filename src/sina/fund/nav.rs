@@ -0,0 +1,135 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, JsonFormat, JsonReader, Schema, SerReader},
+};
+use reqwest::{
+    header::{HeaderValue, REFERER},
+    Method, Request, Url,
+};
+use serde_json::Value;
+use std::io::Cursor;
+
+use crate::{
+    utils::{HttpClient, JsonUtils},
+    DataResult, DataResultFormat, HistoryData,
+};
+
+///
+/// 东方财富网-天天基金-开放式基金-历史净值(单位净值、累计净值、日增长率)
+/// http://fundf10.eastmoney.com/jjjz_000001.html
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyFundNavDataSource {}
+
+impl EastmoneyFundNavDataSource {
+    /// 每页条数
+    const PAGE_SIZE: usize = 20;
+
+    fn request(symbol: &str, start: NaiveDate, end: NaiveDate, page: usize) -> Request {
+        let url = Url::parse_with_params(
+            "http://api.fund.eastmoney.com/f10/lsjz",
+            &[
+                ("callback", "jQuery18306596328894644803_1571038362181"),
+                ("fundCode", symbol),
+                ("pageIndex", &page.to_string()),
+                (
+                    "pageSize",
+                    &EastmoneyFundNavDataSource::PAGE_SIZE.to_string(),
+                ),
+                ("startDate", &start.format("%Y-%m-%d").to_string()),
+                ("endDate", &end.format("%Y-%m-%d").to_string()),
+                ("_", "1571038362181"),
+            ],
+        )
+        .unwrap();
+
+        // 接口校验 referer
+        let mut request = Request::new(Method::GET, url);
+        request.headers_mut().insert(
+            REFERER,
+            HeaderValue::from_static("http://fundf10.eastmoney.com/"),
+        );
+
+        request
+    }
+}
+
+impl DataResultFormat for EastmoneyFundNavDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(JsonUtils::unwrap_jsonp(&body))?;
+
+            let df = match json.pointer("/Data/LSJZList") {
+                Some(Value::Array(rows)) if !rows.is_empty() => {
+                    let file = Cursor::new(serde_json::to_string(rows)?);
+                    JsonReader::new(file)
+                        .with_json_format(JsonFormat::Json)
+                        .finish()?
+                }
+                _ => DataFrame::empty(),
+            };
+
+            return Ok(DataResult::<DataFrame>::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("FSRQ", "净值日期"),
+            ("DWJZ", "单位净值"),
+            ("LJJZ", "累计净值"),
+            ("JZZZL", "日增长率"),
+            ("SGZT", "申购状态"),
+            ("SHZT", "赎回状态"),
+            ("FHSP", "分红送配"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("净值日期".to_string(), DataType::Utf8);
+        schema.with_column("单位净值".to_string(), DataType::Float64);
+        schema.with_column("累计净值".to_string(), DataType::Float64);
+        schema.with_column("日增长率".to_string(), DataType::Float64);
+        schema.with_column("申购状态".to_string(), DataType::Utf8);
+        schema.with_column("赎回状态".to_string(), DataType::Utf8);
+        schema.with_column("分红送配".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyFundNavDataSource {
+    ///
+    /// 开放式基金日净值，market 不区分市场传空即可，symbol 为基金代码，如 000001
+    /// :return: 代码,净值日期,单位净值,累计净值,日增长率,申购状态,赎回状态,分红送配
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let data_frame =
+            HttpClient::exec_by_pages(&self, EastmoneyFundNavDataSource::PAGE_SIZE, |page| {
+                EastmoneyFundNavDataSource::request(symbol, start, end, page)
+            })
+            .await?;
+
+        let df = data_frame
+            .lazy()
+            .select([lit(symbol).alias("代码"), col("*")])
+            .collect()?;
+
+        Ok(DataResult::new(symbol.to_string(), df))
+    }
+}
@@ -0,0 +1,340 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::anyhow;
+use polars::prelude::{DataFrame, DataType, NamedFrom, Series};
+
+///
+/// 主力合约切换依据
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RollBy {
+    /// 持仓量最大的合约
+    OpenInterest,
+    /// 成交量最大的合约
+    Volume,
+}
+
+///
+/// 连续合约复权方式
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdjustType {
+    /// 不复权，直接拼接主力合约行情
+    None,
+    /// 后向价差复权，换月价差累加到历史价格，最新价格与主力合约一致
+    BackAdjusted,
+    /// 后向比例复权，换月价格比例累乘到历史价格，最新价格与主力合约一致
+    RatioAdjusted,
+}
+
+///
+/// 主力合约及连续合约
+///
+/// 输入为同一品种各合约的日行情，列为 symbol,日期,开盘,最高,最低,收盘,成交量,持仓量；
+/// 每日按 roll_by 选出最大的合约，次一交易日切换为主力合约，且只向远月切换不回切
+///
+#[derive(Clone, Debug)]
+pub struct ContinuousContract {
+    pub roll_by: RollBy,
+    pub adjust: AdjustType,
+}
+
+///
+/// 连续合约结果
+///
+#[derive(Clone, Debug)]
+pub struct ContinuousData {
+    /// 连续合约行情: 日期,symbol(当日主力合约),开盘,最高,最低,收盘,成交量,持仓量,复权因子
+    pub data: DataFrame,
+    /// 换月记录: 日期(切换生效日),旧合约,新合约,旧合约收盘,新合约收盘,价差,比例
+    pub rolls: DataFrame,
+}
+
+#[derive(Clone, Debug)]
+struct Bar {
+    symbol: String,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    open_interest: f64,
+}
+
+#[derive(Clone, Debug)]
+struct Roll {
+    /// 新合约在连续合约中的起始位置
+    index: usize,
+    date: String,
+    from: String,
+    to: String,
+    from_close: f64,
+    to_close: f64,
+}
+
+impl Roll {
+    fn gap(&self) -> f64 {
+        self.to_close - self.from_close
+    }
+
+    fn ratio(&self) -> f64 {
+        if self.from_close == 0.0 {
+            1.0
+        } else {
+            self.to_close / self.from_close
+        }
+    }
+}
+
+impl ContinuousContract {
+    ///
+    /// 由各合约日行情构造主力连续合约
+    ///
+    pub fn build(&self, bars: &DataFrame) -> anyhow::Result<ContinuousData> {
+        let days = ContinuousContract::group_by_date(bars)?;
+
+        let mut main: Option<String> = None;
+        let mut pending: Option<String> = None;
+        let mut prev_day: Option<&HashMap<String, Bar>> = None;
+
+        let mut dates: Vec<&str> = vec![];
+        let mut series: Vec<&Bar> = vec![];
+        let mut rolls: Vec<Roll> = vec![];
+
+        for (date, day) in days.iter() {
+            // 前一交易日选出的主力合约今日生效，主力合约无行情(如已到期)时按今日重新选择
+            let next = match (pending.take(), &main) {
+                (Some(p), _) if day.contains_key(&p) => Some(p),
+                (_, Some(m)) if day.contains_key(m) => None,
+                _ => self.select(date, day, None),
+            };
+
+            if let Some(next) = next {
+                if let Some(from) = &main {
+                    let closes = prev_day
+                        .and_then(|p| Some((p.get(from)?.close, p.get(&next)?.close)))
+                        .or_else(|| Some((day.get(from)?.close, day.get(&next)?.close)));
+                    let (from_close, to_close) = closes.unwrap_or((0.0, 0.0));
+
+                    rolls.push(Roll {
+                        index: series.len(),
+                        date: date.clone(),
+                        from: from.clone(),
+                        to: next.clone(),
+                        from_close,
+                        to_close,
+                    });
+                }
+                main = Some(next);
+            }
+
+            let Some(current) = main.as_ref().and_then(|m| day.get(m)) else {
+                continue;
+            };
+            dates.push(date);
+            series.push(current);
+
+            pending = self.select(date, day, main.as_deref());
+            prev_day = Some(day);
+        }
+
+        Ok(ContinuousData {
+            data: self.adjust_series(&dates, &series, &rolls)?,
+            rolls: ContinuousContract::rolls_frame(&rolls)?,
+        })
+    }
+
+    ///
+    /// 选出 roll_by 最大的合约，给定当前主力合约时只选交割月更远的合约
+    ///
+    fn select(
+        &self,
+        date: &str,
+        day: &HashMap<String, Bar>,
+        current: Option<&str>,
+    ) -> Option<String> {
+        let value = |bar: &Bar| match self.roll_by {
+            RollBy::OpenInterest => bar.open_interest,
+            RollBy::Volume => bar.volume,
+        };
+        let month = |symbol: &str| {
+            (
+                ContinuousContract::delivery(symbol, date),
+                symbol.to_string(),
+            )
+        };
+
+        let best = day.values().max_by(|a, b| {
+            value(a)
+                .total_cmp(&value(b))
+                .then_with(|| month(&a.symbol).cmp(&month(&b.symbol)))
+        })?;
+
+        match current {
+            Some(current) if month(&best.symbol) <= month(current) => None,
+            _ => Some(best.symbol.clone()),
+        }
+    }
+
+    ///
+    /// 由合约代码解析交割年月，如 rb2401 -> (2024, 1)
+    ///
+    /// 郑商所合约年份只有一位，如 MA001，按交易日期取不早于交易年份的最近年份，
+    /// 2019-12-02 的 MA001 为 2020年1月
+    ///
+    fn delivery(symbol: &str, date: &str) -> Option<(i32, u32)> {
+        let digits: String = symbol
+            .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        let month: u32 = digits.get(digits.len().checked_sub(2)?..)?.parse().ok()?;
+        let year: i32 = digits.get(..digits.len() - 2)?.parse().ok()?;
+
+        let year = match digits.len() {
+            4 => 2000 + year,
+            3 => {
+                let trade_year: i32 = date.get(..4)?.parse().ok()?;
+                let year = trade_year - trade_year % 10 + year;
+                if year < trade_year {
+                    year + 10
+                } else {
+                    year
+                }
+            }
+            _ => return None,
+        };
+
+        Some((year, month))
+    }
+
+    fn adjust_series(
+        &self,
+        dates: &[&str],
+        series: &[&Bar],
+        rolls: &[Roll],
+    ) -> anyhow::Result<DataFrame> {
+        // 每个位置的复权因子：价差复权为之后所有换月价差之和，比例复权为之后所有换月比例之积
+        let factors: Vec<f64> = (0..series.len())
+            .map(|i| {
+                let after = rolls.iter().filter(|r| r.index > i);
+                match self.adjust {
+                    AdjustType::None => 0.0,
+                    AdjustType::BackAdjusted => after.map(Roll::gap).sum(),
+                    AdjustType::RatioAdjusted => after.map(Roll::ratio).product(),
+                }
+            })
+            .collect();
+
+        let price = |f: fn(&Bar) -> f64| -> Vec<f64> {
+            series
+                .iter()
+                .zip(&factors)
+                .map(|(bar, factor)| match self.adjust {
+                    AdjustType::None => f(bar),
+                    AdjustType::BackAdjusted => f(bar) + factor,
+                    AdjustType::RatioAdjusted => f(bar) * factor,
+                })
+                .collect()
+        };
+
+        let df = DataFrame::new(vec![
+            Series::new("日期", dates),
+            Series::new(
+                "symbol",
+                series.iter().map(|b| b.symbol.as_str()).collect::<Vec<_>>(),
+            ),
+            Series::new("开盘", price(|b| b.open)),
+            Series::new("最高", price(|b| b.high)),
+            Series::new("最低", price(|b| b.low)),
+            Series::new("收盘", price(|b| b.close)),
+            Series::new(
+                "成交量",
+                series.iter().map(|b| b.volume).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "持仓量",
+                series.iter().map(|b| b.open_interest).collect::<Vec<_>>(),
+            ),
+            Series::new("复权因子", factors),
+        ])?;
+
+        Ok(df)
+    }
+
+    fn rolls_frame(rolls: &[Roll]) -> anyhow::Result<DataFrame> {
+        let df = DataFrame::new(vec![
+            Series::new(
+                "日期",
+                rolls.iter().map(|r| r.date.as_str()).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "旧合约",
+                rolls.iter().map(|r| r.from.as_str()).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "新合约",
+                rolls.iter().map(|r| r.to.as_str()).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "旧合约收盘",
+                rolls.iter().map(|r| r.from_close).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "新合约收盘",
+                rolls.iter().map(|r| r.to_close).collect::<Vec<_>>(),
+            ),
+            Series::new("价差", rolls.iter().map(Roll::gap).collect::<Vec<_>>()),
+            Series::new("比例", rolls.iter().map(Roll::ratio).collect::<Vec<_>>()),
+        ])?;
+
+        Ok(df)
+    }
+
+    ///
+    /// 按日期分组各合约行情，日期升序
+    ///
+    fn group_by_date(bars: &DataFrame) -> anyhow::Result<BTreeMap<String, HashMap<String, Bar>>> {
+        let f64_column = |name: &str| -> anyhow::Result<Vec<Option<f64>>> {
+            let column = bars
+                .column(name)
+                .map_err(|_| anyhow!("合约行情缺少列: {}", name))?
+                .cast(&DataType::Float64)?;
+            Ok(column.f64()?.into_iter().collect())
+        };
+
+        let symbols = bars.column("symbol")?.utf8()?.clone();
+        let dates = bars.column("日期")?.utf8()?.clone();
+        let open = f64_column("开盘")?;
+        let high = f64_column("最高")?;
+        let low = f64_column("最低")?;
+        let close = f64_column("收盘")?;
+        let volume = f64_column("成交量")?;
+        let open_interest = f64_column("持仓量")?;
+
+        let mut days: BTreeMap<String, HashMap<String, Bar>> = BTreeMap::new();
+        for (i, (symbol, date)) in symbols.into_iter().zip(&dates).enumerate() {
+            // 缺少价格的行(如停牌、未上市)跳过，不参与主力合约选择
+            let (Some(symbol), Some(date), Some(open), Some(high), Some(low), Some(close)) =
+                (symbol, date, open[i], high[i], low[i], close[i])
+            else {
+                continue;
+            };
+
+            days.entry(date.to_string()).or_default().insert(
+                symbol.to_string(),
+                Bar {
+                    symbol: symbol.to_string(),
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume: volume[i].unwrap_or(0.0),
+                    open_interest: open_interest[i].unwrap_or(0.0),
+                },
+            );
+        }
+
+        Ok(days)
+    }
+}
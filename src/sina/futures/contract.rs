@@ -0,0 +1,521 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Datelike, Duration, NaiveDate, Weekday},
+    lazy::dsl::col,
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+
+use crate::{
+    sina::futures::sina::SinaFuturesSpotDataSource, DataResult, DataResultFormat, RealTimeData,
+};
+
+///
+/// 期货交易所
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exchange {
+    /// 上海期货交易所
+    Shfe,
+    /// 上海国际能源交易中心
+    Ine,
+    /// 大连商品交易所
+    Dce,
+    /// 郑州商品交易所
+    Czce,
+    /// 广州期货交易所
+    Gfex,
+    /// 中国金融期货交易所
+    Cffex,
+}
+
+impl Exchange {
+    pub fn code(&self) -> &str {
+        match self {
+            Exchange::Shfe => "SHFE",
+            Exchange::Ine => "INE",
+            Exchange::Dce => "DCE",
+            Exchange::Czce => "CZCE",
+            Exchange::Gfex => "GFEX",
+            Exchange::Cffex => "CFFEX",
+        }
+    }
+}
+
+///
+/// 最后交易日规则，按工作日计算，未考虑法定节假日
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LastTradingDay {
+    /// 交割月份的第n日，遇非交易日顺延，如上期所 15 日
+    DayOfMonth(u32),
+    /// 交割月份的第n个交易日，如大商所、郑商所、广期所第 10 个交易日
+    NthTradingDay(u32),
+    /// 交割月份的第n个星期几，如股指期货第三个周五、国债期货第二个周五
+    NthWeekday(u32, Weekday),
+    /// 交割月份前一月的最后一个交易日，如原油
+    LastTradingDayOfPrevMonth,
+}
+
+impl LastTradingDay {
+    ///
+    /// 指定年月的最后交易日
+    ///
+    pub fn date(&self, year: i32, month: u32) -> NaiveDate {
+        let first = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+        match *self {
+            LastTradingDay::DayOfMonth(day) => {
+                let mut date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+                while is_weekend(date) {
+                    date += Duration::days(1);
+                }
+                date
+            }
+            LastTradingDay::NthTradingDay(n) => {
+                let mut date = first;
+                let mut count = u32::from(!is_weekend(date));
+                while count < n {
+                    date += Duration::days(1);
+                    if !is_weekend(date) {
+                        count += 1;
+                    }
+                }
+                date
+            }
+            LastTradingDay::NthWeekday(n, weekday) => {
+                let offset = (7 + weekday.num_days_from_monday()
+                    - first.weekday().num_days_from_monday())
+                    % 7;
+                first + Duration::days((offset + 7 * (n - 1)) as i64)
+            }
+            LastTradingDay::LastTradingDayOfPrevMonth => {
+                let mut date = first - Duration::days(1);
+                while is_weekend(date) {
+                    date -= Duration::days(1);
+                }
+                date
+            }
+        }
+    }
+}
+
+///
+/// 合约挂牌规则
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListingRule {
+    /// 挂牌未来12个月内的交割月份合约
+    Months12,
+    /// 当月、下月及随后两个季月，如股指期货
+    IndexFutures,
+    /// 最近的三个季月，如国债期货
+    BondFutures,
+}
+
+///
+/// 期货品种合约规格
+///
+/// 保证金比例、涨跌停板幅度为交易所标准值，交易所会根据行情临时调整
+///
+#[derive(Clone, Debug)]
+pub struct ContractSpec {
+    /// 品种代码，如 rb、IF、MA
+    pub product: &'static str,
+    pub name: &'static str,
+    pub exchange: Exchange,
+    /// 合约乘数(交易单位)
+    pub multiplier: f64,
+    /// 最小变动价位
+    pub tick_size: f64,
+    /// 最低交易保证金比例
+    pub margin_ratio: f64,
+    /// 涨跌停板幅度
+    pub price_limit: f64,
+    /// 交割月份
+    pub delivery_months: &'static [u32],
+    pub last_trading_day: LastTradingDay,
+    pub listing: ListingRule,
+}
+
+const MONTHS_ALL: &[u32] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+const MONTHS_ODD: &[u32] = &[1, 3, 5, 7, 9, 11];
+const MONTHS_QUARTER: &[u32] = &[3, 6, 9, 12];
+
+macro_rules! spec {
+    ($product:expr, $name:expr, $exchange:expr, $multiplier:expr, $tick:expr, $margin:expr, $limit:expr, $months:expr, $last:expr) => {
+        spec!(
+            $product,
+            $name,
+            $exchange,
+            $multiplier,
+            $tick,
+            $margin,
+            $limit,
+            $months,
+            $last,
+            ListingRule::Months12
+        )
+    };
+    ($product:expr, $name:expr, $exchange:expr, $multiplier:expr, $tick:expr, $margin:expr, $limit:expr, $months:expr, $last:expr, $listing:expr) => {
+        ContractSpec {
+            product: $product,
+            name: $name,
+            exchange: $exchange,
+            multiplier: $multiplier,
+            tick_size: $tick,
+            margin_ratio: $margin,
+            price_limit: $limit,
+            delivery_months: $months,
+            last_trading_day: $last,
+            listing: $listing,
+        }
+    };
+}
+
+use Exchange::*;
+use LastTradingDay::*;
+
+///
+/// 内置品种合约规格表
+///
+#[rustfmt::skip]
+pub const CONTRACT_SPECS: &[ContractSpec] = &[
+    // 上海期货交易所
+    spec!("cu", "铜", Shfe, 5.0, 10.0, 0.10, 0.08, MONTHS_ALL, DayOfMonth(15)),
+    spec!("al", "铝", Shfe, 5.0, 5.0, 0.10, 0.08, MONTHS_ALL, DayOfMonth(15)),
+    spec!("zn", "锌", Shfe, 5.0, 5.0, 0.10, 0.08, MONTHS_ALL, DayOfMonth(15)),
+    spec!("pb", "铅", Shfe, 5.0, 5.0, 0.10, 0.08, MONTHS_ALL, DayOfMonth(15)),
+    spec!("ni", "镍", Shfe, 1.0, 10.0, 0.14, 0.12, MONTHS_ALL, DayOfMonth(15)),
+    spec!("sn", "锡", Shfe, 1.0, 10.0, 0.14, 0.12, MONTHS_ALL, DayOfMonth(15)),
+    spec!("au", "黄金", Shfe, 1000.0, 0.02, 0.08, 0.07, MONTHS_ALL, DayOfMonth(15)),
+    spec!("ag", "白银", Shfe, 15.0, 1.0, 0.10, 0.09, MONTHS_ALL, DayOfMonth(15)),
+    spec!("rb", "螺纹钢", Shfe, 10.0, 1.0, 0.09, 0.07, MONTHS_ALL, DayOfMonth(15)),
+    spec!("hc", "热轧卷板", Shfe, 10.0, 1.0, 0.09, 0.07, MONTHS_ALL, DayOfMonth(15)),
+    spec!("ss", "不锈钢", Shfe, 5.0, 5.0, 0.09, 0.07, MONTHS_ALL, DayOfMonth(15)),
+    spec!("fu", "燃料油", Shfe, 10.0, 1.0, 0.10, 0.08, MONTHS_ALL, LastTradingDayOfPrevMonth),
+    spec!("bu", "沥青", Shfe, 10.0, 1.0, 0.10, 0.08, MONTHS_ALL, DayOfMonth(15)),
+    spec!("ru", "天然橡胶", Shfe, 10.0, 5.0, 0.09, 0.07, &[1, 3, 4, 5, 6, 7, 8, 9, 10, 11], DayOfMonth(15)),
+    spec!("sp", "纸浆", Shfe, 10.0, 2.0, 0.09, 0.07, MONTHS_ALL, DayOfMonth(15)),
+    // 上海国际能源交易中心
+    spec!("sc", "原油", Ine, 1000.0, 0.1, 0.10, 0.08, MONTHS_ALL, LastTradingDayOfPrevMonth),
+    spec!("lu", "低硫燃料油", Ine, 10.0, 1.0, 0.10, 0.08, MONTHS_ALL, LastTradingDayOfPrevMonth),
+    spec!("nr", "20号胶", Ine, 10.0, 5.0, 0.09, 0.07, MONTHS_ALL, DayOfMonth(15)),
+    spec!("bc", "国际铜", Ine, 5.0, 10.0, 0.10, 0.08, MONTHS_ALL, DayOfMonth(15)),
+    // 大连商品交易所
+    spec!("a", "豆一", Dce, 10.0, 1.0, 0.08, 0.06, MONTHS_ODD, NthTradingDay(10)),
+    spec!("m", "豆粕", Dce, 10.0, 1.0, 0.08, 0.06, &[1, 3, 5, 7, 8, 9, 11, 12], NthTradingDay(10)),
+    spec!("y", "豆油", Dce, 10.0, 2.0, 0.08, 0.06, &[1, 3, 5, 7, 8, 9, 11, 12], NthTradingDay(10)),
+    spec!("p", "棕榈油", Dce, 10.0, 2.0, 0.08, 0.06, MONTHS_ALL, NthTradingDay(10)),
+    spec!("c", "玉米", Dce, 10.0, 1.0, 0.08, 0.06, MONTHS_ODD, NthTradingDay(10)),
+    spec!("cs", "玉米淀粉", Dce, 10.0, 1.0, 0.08, 0.06, MONTHS_ODD, NthTradingDay(10)),
+    spec!("jd", "鸡蛋", Dce, 10.0, 1.0, 0.08, 0.06, MONTHS_ALL, NthTradingDay(10)),
+    spec!("lh", "生猪", Dce, 16.0, 5.0, 0.12, 0.08, MONTHS_ODD, NthTradingDay(10)),
+    spec!("i", "铁矿石", Dce, 100.0, 0.5, 0.11, 0.09, MONTHS_ALL, NthTradingDay(10)),
+    spec!("j", "焦炭", Dce, 100.0, 0.5, 0.11, 0.09, MONTHS_ALL, NthTradingDay(10)),
+    spec!("jm", "焦煤", Dce, 60.0, 0.5, 0.11, 0.09, MONTHS_ALL, NthTradingDay(10)),
+    spec!("l", "塑料", Dce, 5.0, 1.0, 0.07, 0.05, MONTHS_ALL, NthTradingDay(10)),
+    spec!("pp", "聚丙烯", Dce, 5.0, 1.0, 0.07, 0.05, MONTHS_ALL, NthTradingDay(10)),
+    spec!("v", "PVC", Dce, 5.0, 1.0, 0.07, 0.05, MONTHS_ALL, NthTradingDay(10)),
+    spec!("eg", "乙二醇", Dce, 10.0, 1.0, 0.08, 0.06, MONTHS_ALL, NthTradingDay(10)),
+    spec!("eb", "苯乙烯", Dce, 5.0, 1.0, 0.08, 0.06, MONTHS_ALL, NthTradingDay(10)),
+    spec!("pg", "液化石油气", Dce, 20.0, 1.0, 0.08, 0.06, MONTHS_ALL, NthTradingDay(10)),
+    // 郑州商品交易所
+    spec!("SR", "白糖", Czce, 10.0, 1.0, 0.07, 0.05, MONTHS_ODD, NthTradingDay(10)),
+    spec!("CF", "棉花", Czce, 5.0, 5.0, 0.07, 0.05, MONTHS_ODD, NthTradingDay(10)),
+    spec!("TA", "PTA", Czce, 5.0, 2.0, 0.07, 0.05, MONTHS_ALL, NthTradingDay(10)),
+    spec!("MA", "甲醇", Czce, 10.0, 1.0, 0.07, 0.05, MONTHS_ALL, NthTradingDay(10)),
+    spec!("FG", "玻璃", Czce, 20.0, 1.0, 0.09, 0.07, MONTHS_ALL, NthTradingDay(10)),
+    spec!("SA", "纯碱", Czce, 20.0, 1.0, 0.09, 0.07, MONTHS_ALL, NthTradingDay(10)),
+    spec!("UR", "尿素", Czce, 20.0, 1.0, 0.08, 0.06, MONTHS_ALL, NthTradingDay(10)),
+    spec!("RM", "菜粕", Czce, 10.0, 1.0, 0.09, 0.07, &[1, 3, 5, 7, 8, 9, 11], NthTradingDay(10)),
+    spec!("OI", "菜油", Czce, 10.0, 1.0, 0.09, 0.07, MONTHS_ODD, NthTradingDay(10)),
+    spec!("AP", "苹果", Czce, 10.0, 1.0, 0.10, 0.08, &[1, 3, 4, 5, 10, 11, 12], NthTradingDay(10)),
+    spec!("SF", "硅铁", Czce, 5.0, 2.0, 0.09, 0.07, MONTHS_ALL, NthTradingDay(10)),
+    spec!("SM", "锰硅", Czce, 5.0, 2.0, 0.09, 0.07, MONTHS_ALL, NthTradingDay(10)),
+    spec!("PF", "短纤", Czce, 5.0, 2.0, 0.08, 0.06, MONTHS_ALL, NthTradingDay(10)),
+    // 广州期货交易所
+    spec!("si", "工业硅", Gfex, 5.0, 5.0, 0.09, 0.07, MONTHS_ALL, NthTradingDay(10)),
+    spec!("lc", "碳酸锂", Gfex, 1.0, 50.0, 0.09, 0.07, MONTHS_ALL, NthTradingDay(10)),
+    // 中国金融期货交易所
+    spec!("IF", "沪深300股指", Cffex, 300.0, 0.2, 0.12, 0.10, MONTHS_ALL, NthWeekday(3, Weekday::Fri), ListingRule::IndexFutures),
+    spec!("IH", "上证50股指", Cffex, 300.0, 0.2, 0.12, 0.10, MONTHS_ALL, NthWeekday(3, Weekday::Fri), ListingRule::IndexFutures),
+    spec!("IC", "中证500股指", Cffex, 200.0, 0.2, 0.14, 0.10, MONTHS_ALL, NthWeekday(3, Weekday::Fri), ListingRule::IndexFutures),
+    spec!("IM", "中证1000股指", Cffex, 200.0, 0.2, 0.14, 0.10, MONTHS_ALL, NthWeekday(3, Weekday::Fri), ListingRule::IndexFutures),
+    spec!("TS", "2年期国债", Cffex, 20000.0, 0.002, 0.005, 0.005, MONTHS_QUARTER, NthWeekday(2, Weekday::Fri), ListingRule::BondFutures),
+    spec!("TF", "5年期国债", Cffex, 10000.0, 0.005, 0.012, 0.012, MONTHS_QUARTER, NthWeekday(2, Weekday::Fri), ListingRule::BondFutures),
+    spec!("T", "10年期国债", Cffex, 10000.0, 0.005, 0.02, 0.02, MONTHS_QUARTER, NthWeekday(2, Weekday::Fri), ListingRule::BondFutures),
+    spec!("TL", "30年期国债", Cffex, 10000.0, 0.01, 0.035, 0.035, MONTHS_QUARTER, NthWeekday(2, Weekday::Fri), ListingRule::BondFutures),
+];
+
+impl ContractSpec {
+    ///
+    /// 期货品种合约规格表
+    /// :return: 品种,名称,交易所,合约乘数,最小变动价位,保证金比例,涨跌停板幅度,交割月份
+    ///
+    pub fn specs() -> anyhow::Result<DataFrame> {
+        let specs = CONTRACT_SPECS;
+        let df = DataFrame::new(vec![
+            Series::new("品种", specs.iter().map(|s| s.product).collect::<Vec<_>>()),
+            Series::new("名称", specs.iter().map(|s| s.name).collect::<Vec<_>>()),
+            Series::new(
+                "交易所",
+                specs.iter().map(|s| s.exchange.code()).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "合约乘数",
+                specs.iter().map(|s| s.multiplier).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "最小变动价位",
+                specs.iter().map(|s| s.tick_size).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "保证金比例",
+                specs.iter().map(|s| s.margin_ratio).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "涨跌停板幅度",
+                specs.iter().map(|s| s.price_limit).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "交割月份",
+                specs
+                    .iter()
+                    .map(|s| {
+                        s.delivery_months
+                            .iter()
+                            .map(|m| m.to_string())
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        ])?;
+
+        Ok(df)
+    }
+
+    ///
+    /// 按品种代码查找合约规格，不区分大小写
+    ///
+    pub fn get(product: &str) -> Option<&'static ContractSpec> {
+        CONTRACT_SPECS
+            .iter()
+            .find(|s| s.product.eq_ignore_ascii_case(product))
+    }
+
+    ///
+    /// 价格变动对应的盈亏 = 价格变动 * 合约乘数 * 手数
+    ///
+    pub fn pnl(&self, price_change: f64, lots: f64) -> f64 {
+        price_change * self.multiplier * lots
+    }
+
+    ///
+    /// 按最低保证金比例计算的交易保证金 = 价格 * 合约乘数 * 手数 * 保证金比例
+    ///
+    pub fn margin(&self, price: f64, lots: f64) -> f64 {
+        price * self.multiplier * lots * self.margin_ratio
+    }
+
+    ///
+    /// 合约代码，郑商所为品种加年份末位及月份(MA401)，其余交易所为品种加年月(rb2401、IF2401)
+    ///
+    pub fn symbol(&self, year: i32, month: u32) -> String {
+        match self.exchange {
+            Exchange::Czce => format!("{}{}{:02}", self.product, year % 10, month),
+            _ => format!("{}{:02}{:02}", self.product, year % 100, month),
+        }
+    }
+
+    ///
+    /// 指定交割年月合约的最后交易日
+    ///
+    pub fn last_trading_day(&self, year: i32, month: u32) -> NaiveDate {
+        self.last_trading_day.date(year, month)
+    }
+
+    ///
+    /// 指定日期挂牌交易的合约，返回 (合约代码, 最后交易日)，按交割月份升序
+    ///
+    pub fn active_contracts(&self, date: NaiveDate) -> Vec<(String, NaiveDate)> {
+        // 未到最后交易日的候选交割年月
+        let candidates: Vec<(i32, u32, NaiveDate)> = (0..13)
+            .map(|i| {
+                let months = date.year() * 12 + date.month0() as i32 + i;
+                (months / 12, (months % 12) as u32 + 1)
+            })
+            .filter(|(_, month)| self.delivery_months.contains(month))
+            .map(|(year, month)| (year, month, self.last_trading_day(year, month)))
+            .filter(|(_, _, last)| *last >= date)
+            .collect();
+
+        let contracts: Vec<&(i32, u32, NaiveDate)> = match self.listing {
+            ListingRule::Months12 => candidates.iter().take(12).collect(),
+            ListingRule::IndexFutures => {
+                let mut contracts: Vec<_> = candidates.iter().take(2).collect();
+                let quarters = candidates
+                    .iter()
+                    .skip(2)
+                    .filter(|(_, month, _)| MONTHS_QUARTER.contains(month))
+                    .take(2);
+                contracts.extend(quarters);
+                contracts
+            }
+            ListingRule::BondFutures => candidates.iter().take(3).collect(),
+        };
+
+        contracts
+            .into_iter()
+            .map(|(year, month, last)| (self.symbol(*year, *month), *last))
+            .collect()
+    }
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+///
+/// 期货品种在指定日期的挂牌合约，结合内置合约规格与新浪财经实时行情，
+/// 计算合约价值与每手保证金
+///
+#[derive(Clone, Debug)]
+pub struct FuturesContractDataSource {
+    /// 品种代码，如 rb、IF、MA
+    pub product: String,
+    /// 查询日期
+    pub date: NaiveDate,
+}
+
+impl FuturesContractDataSource {
+    ///
+    /// 挂牌合约列表
+    /// :return: symbol,品种,交易所,最后交易日,合约乘数,最小变动价位,保证金比例,涨跌停板幅度
+    ///
+    pub fn contracts(&self) -> anyhow::Result<DataFrame> {
+        let spec = ContractSpec::get(&self.product)
+            .ok_or_else(|| anyhow::anyhow!("未知期货品种: {}", self.product))?;
+        let contracts = spec.active_contracts(self.date);
+        let n = contracts.len();
+
+        let df = DataFrame::new(vec![
+            Series::new(
+                "symbol",
+                contracts
+                    .iter()
+                    .map(|(s, _)| s.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new("品种", vec![spec.product; n]),
+            Series::new("交易所", vec![spec.exchange.code(); n]),
+            Series::new(
+                "最后交易日",
+                contracts
+                    .iter()
+                    .map(|(_, d)| d.format("%Y-%m-%d").to_string())
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new("合约乘数", vec![spec.multiplier; n]),
+            Series::new("最小变动价位", vec![spec.tick_size; n]),
+            Series::new("保证金比例", vec![spec.margin_ratio; n]),
+            Series::new("涨跌停板幅度", vec![spec.price_limit; n]),
+        ])?;
+
+        Ok(df)
+    }
+
+    ///
+    /// 新浪行情代码，统一为大写品种加四位年月，如 RB2401、MA2401
+    ///
+    fn sina_symbol(&self, symbol: &str) -> String {
+        let product = symbol.trim_end_matches(|c: char| c.is_ascii_digit());
+        let ym = &symbol[product.len()..];
+        // 郑商所合约年份只有末位，小于当前年份末位时为下一个十年
+        let ym = if ym.len() == 3 {
+            let year = self.date.year();
+            let mut decade = year % 100 / 10;
+            if ym[..1] < year.to_string()[3..] {
+                decade = (decade + 1) % 10;
+            }
+            format!("{}{}", decade, ym)
+        } else {
+            ym.to_string()
+        };
+
+        format!("{}{}", product.to_uppercase(), ym)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for FuturesContractDataSource {
+    ///
+    /// 挂牌合约及实时行情
+    /// :return: 挂牌合约列表各列及 最新价,结算价,昨结算,持仓量,合约价值,每手保证金
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let contracts = self.contracts()?;
+
+        let symbols: Vec<String> = contracts
+            .column("symbol")?
+            .utf8()?
+            .into_iter()
+            .flatten()
+            .map(|s| self.sina_symbol(s))
+            .collect();
+        let spot = SinaFuturesSpotDataSource {
+            symbols: symbols.clone(),
+        };
+        let quotes = match spot.real_time_data().await?.data {
+            Some(df) if df.height() > 0 => df,
+            _ => spot.col_schema().as_ref().map(DataFrame::from).unwrap(),
+        };
+
+        let multiplier = col("合约乘数");
+        let df = contracts
+            .hstack(&[Series::new("sina_symbol", symbols)])?
+            .lazy()
+            .left_join(
+                quotes.lazy().select([
+                    col("symbol").alias("sina_symbol"),
+                    col("最新价"),
+                    col("结算价"),
+                    col("昨结算"),
+                    col("持仓量"),
+                ]),
+                col("sina_symbol"),
+                col("sina_symbol"),
+            )
+            .with_columns([
+                (col("最新价") * multiplier.clone()).alias("合约价值"),
+                (col("最新价") * multiplier * col("保证金比例")).alias("每手保证金"),
+            ])
+            .drop_columns(["sina_symbol"])
+            .collect()?;
+
+        Ok(DataResult::new(
+            format!("{}-{}", self.product, self.date),
+            df,
+        ))
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+        schema.with_column("品种".to_string(), DataType::Utf8);
+        schema.with_column("交易所".to_string(), DataType::Utf8);
+        schema.with_column("最后交易日".to_string(), DataType::Utf8);
+        schema.with_column("合约乘数".to_string(), DataType::Float64);
+        schema.with_column("最小变动价位".to_string(), DataType::Float64);
+        schema.with_column("保证金比例".to_string(), DataType::Float64);
+        schema.with_column("涨跌停板幅度".to_string(), DataType::Float64);
+        schema.with_column("最新价".to_string(), DataType::Float64);
+        schema.with_column("结算价".to_string(), DataType::Float64);
+        schema.with_column("昨结算".to_string(), DataType::Float64);
+        schema.with_column("持仓量".to_string(), DataType::Float64);
+        schema.with_column("合约价值".to_string(), DataType::Float64);
+        schema.with_column("每手保证金".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
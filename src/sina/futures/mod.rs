@@ -0,0 +1,8 @@
+/// 主力合约及连续合约
+pub mod continuous;
+
+/// 合约规格及到期日历
+pub mod contract;
+
+/// 新浪财经数据源
+pub mod sina;
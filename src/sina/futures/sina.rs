@@ -0,0 +1,151 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::prelude::{DataFrame, DataType, NamedFrom, Schema, Series};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::sina::SinaHq, utils::HttpClient, DataResult, DataResultFormat, HttpSource,
+    RealTimeData,
+};
+
+///
+/// 新浪财经-国内商品期货(上期所、大商所、郑商所、上期能源、广期所)-实时行情
+/// https://finance.sina.com.cn/futuremarket/
+///
+/// 大量抓取容易封IP
+///
+#[derive(Clone, Debug)]
+pub struct SinaFuturesSpotDataSource {
+    /// 合约代码，如 RB2401、M2405、SC2312、SI2401，品种代码加 0 为主力连续合约，如 RB0
+    pub symbols: Vec<String>,
+}
+
+impl SinaFuturesSpotDataSource {
+    ///
+    /// 行情字段顺序: 名称,时间,开盘,最高,最低,昨收,买价,卖价,最新价,结算价,昨结算,买量,卖量,持仓量,成交量,交易所,品种,日期
+    ///
+    const FIELDS: [&'static str; 18] = [
+        "name",
+        "time",
+        "open",
+        "high",
+        "low",
+        "last_close",
+        "bid",
+        "ask",
+        "last",
+        "settlement",
+        "pre_settlement",
+        "bid_volume",
+        "ask_volume",
+        "open_interest",
+        "volume",
+        "exchange",
+        "product",
+        "date",
+    ];
+}
+
+impl HttpSource for SinaFuturesSpotDataSource {
+    fn request(&self) -> Request {
+        let list: Vec<String> = self
+            .symbols
+            .iter()
+            .map(|s| format!("nf_{}", s.to_uppercase()))
+            .collect();
+
+        SinaHq::request(&list)
+    }
+}
+
+impl DataResultFormat for SinaFuturesSpotDataSource {
+    ///
+    /// 解析 var hq_str_nf_RB2401="螺纹钢2401,145958,3675.000,...";
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let (symbols, rows): (Vec<&str>, Vec<Vec<&str>>) = SinaHq::parse(&body)
+                .into_iter()
+                .map(|(symbol, values)| (symbol.trim_start_matches("nf_"), values))
+                .unzip();
+            if rows.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let mut columns = vec![Series::new("symbol", symbols)];
+            for (i, field) in SinaFuturesSpotDataSource::FIELDS.iter().enumerate() {
+                let values: Vec<Option<&str>> = rows
+                    .iter()
+                    .map(|r| r.get(i).copied().filter(|v| !v.is_empty()))
+                    .collect();
+                columns.push(Series::new(field, values));
+            }
+
+            return Ok(DataResult::new("".to_string(), DataFrame::new(columns)?));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("symbol", "symbol"),
+            ("name", "名称"),
+            ("date", "日期"),
+            ("time", "时间"),
+            ("last", "最新价"),
+            ("bid", "买价"),
+            ("ask", "卖价"),
+            ("bid_volume", "买量"),
+            ("ask_volume", "卖量"),
+            ("open", "开盘"),
+            ("high", "最高"),
+            ("low", "最低"),
+            ("last_close", "昨收"),
+            ("settlement", "结算价"),
+            ("pre_settlement", "昨结算"),
+            ("open_interest", "持仓量"),
+            ("volume", "成交量"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("时间".to_string(), DataType::Utf8);
+        schema.with_column("最新价".to_string(), DataType::Float64);
+        schema.with_column("买价".to_string(), DataType::Float64);
+        schema.with_column("卖价".to_string(), DataType::Float64);
+        schema.with_column("买量".to_string(), DataType::Float64);
+        schema.with_column("卖量".to_string(), DataType::Float64);
+        schema.with_column("开盘".to_string(), DataType::Float64);
+        schema.with_column("最高".to_string(), DataType::Float64);
+        schema.with_column("最低".to_string(), DataType::Float64);
+        schema.with_column("昨收".to_string(), DataType::Float64);
+        schema.with_column("结算价".to_string(), DataType::Float64);
+        schema.with_column("昨结算".to_string(), DataType::Float64);
+        schema.with_column("持仓量".to_string(), DataType::Float64);
+        schema.with_column("成交量".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for SinaFuturesSpotDataSource {
+    ///
+    /// 指定合约的实时行情
+    /// :return: symbol,名称,日期,时间,最新价,买价,卖价,买量,卖量,开盘,最高,最低,昨收,结算价,昨结算,持仓量,成交量
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
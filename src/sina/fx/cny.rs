@@ -0,0 +1,301 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Duration, Local, NaiveDate},
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::{Method, Request, Url};
+use serde_json::Value;
+
+use crate::{
+    sina::stock::eastmoney::{EastmoneyClist, EastmoneyKline},
+    utils::HttpClient,
+    DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 中国货币网-人民币汇率中间价
+/// https://www.chinamoney.com.cn/chinese/bkccpr/
+///
+/// 返回 日期,货币对,中间价 长表，货币对如 USD/CNY、EUR/CNY、100JPY/CNY
+///
+#[derive(Clone, Debug)]
+pub struct ChinaMoneyCnyFixingDataSource {
+    /// 货币对，如 USD/CNY，None 时返回全部货币对
+    pub pair: Option<String>,
+}
+
+impl ChinaMoneyCnyFixingDataSource {
+    /// 接口单次查询跨度不超过一年
+    const MAX_DAYS: i64 = 365;
+
+    fn request_by_date(start: NaiveDate, end: NaiveDate) -> Request {
+        let url = Url::parse_with_params(
+            "https://www.chinamoney.com.cn/ags/ms/cm-u-bk-ccpr/CcprHisNew",
+            &[
+                ("startDate", start.format("%Y-%m-%d").to_string().as_str()),
+                ("endDate", &end.format("%Y-%m-%d").to_string()),
+                ("currency", ""),
+                ("pageNum", "1"),
+                ("pageSize", "500"),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::POST, url)
+    }
+
+    fn filter_pair(&self, df: DataFrame) -> anyhow::Result<DataFrame> {
+        match &self.pair {
+            Some(pair) => Ok(df
+                .lazy()
+                .filter(col("货币对").eq(lit(pair.as_str())))
+                .collect()?),
+            None => Ok(df),
+        }
+    }
+}
+
+impl HttpSource for ChinaMoneyCnyFixingDataSource {
+    fn request(&self) -> Request {
+        let today = Local::now().date_naive();
+        ChinaMoneyCnyFixingDataSource::request_by_date(today - Duration::days(14), today)
+    }
+}
+
+impl DataResultFormat for ChinaMoneyCnyFixingDataSource {
+    ///
+    /// data.head 为货币对，records 每行为一个日期各货币对的中间价
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(&body)?;
+
+            let pairs: Vec<&str> = match json.pointer("/data/head") {
+                Some(Value::Array(head)) => head.iter().filter_map(|h| h.as_str()).collect(),
+                _ => vec![],
+            };
+
+            let (mut dates, mut names, mut prices) = (vec![], vec![], vec![]);
+            if let Some(Value::Array(records)) = json.get("records") {
+                for record in records {
+                    let Some(date) = record.get("date").and_then(|d| d.as_str()) else {
+                        continue;
+                    };
+                    let Some(Value::Array(values)) = record.get("values") else {
+                        continue;
+                    };
+                    for (pair, value) in pairs.iter().zip(values) {
+                        let price = match value {
+                            Value::String(s) => s.parse::<f64>().ok(),
+                            Value::Number(n) => n.as_f64(),
+                            _ => None,
+                        };
+                        if let Some(price) = price {
+                            dates.push(date);
+                            names.push(*pair);
+                            prices.push(price);
+                        }
+                    }
+                }
+            }
+            if dates.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let df = DataFrame::new(vec![
+                Series::new("date", dates),
+                Series::new("pair", names),
+                Series::new("price", prices),
+            ])?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![("date", "日期"), ("pair", "货币对"), ("price", "中间价")];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("货币对".to_string(), DataType::Utf8);
+        schema.with_column("中间价".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for ChinaMoneyCnyFixingDataSource {
+    ///
+    /// 最新一期人民币汇率中间价(每个交易日 9:15 发布)，发布前为上一交易日，不缓存
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let mut result = HttpClient::exec_and_format(self.request(), self.clone()).await?;
+
+        if let Some(df) = &result.data {
+            let latest = df
+                .column("日期")?
+                .utf8()?
+                .into_iter()
+                .flatten()
+                .max()
+                .map(|d| d.to_string());
+            if let Some(latest) = latest {
+                let df = df
+                    .clone()
+                    .lazy()
+                    .filter(col("日期").eq(lit(latest)))
+                    .collect()?;
+                result.data = Some(self.filter_pair(df)?);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for ChinaMoneyCnyFixingDataSource {
+    ///
+    /// [start, end] 期间人民币汇率中间价，market、symbol 不区分传空即可，按日期倒序
+    ///
+    /// 按年拆分查询，今日之前的区间永久缓存，包含今日的区间尚未定稿，不缓存
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        _symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let today = Local::now().date_naive();
+        let mut data_frame = self.col_schema().as_ref().map(DataFrame::from).unwrap();
+
+        let mut from = start;
+        while from <= end {
+            let to = end.min(from + Duration::days(ChinaMoneyCnyFixingDataSource::MAX_DAYS - 1));
+            let request = ChinaMoneyCnyFixingDataSource::request_by_date(from, to);
+            let result = if to < today {
+                HttpClient::exec_by_permanent_cache(request, self.clone()).await?
+            } else {
+                HttpClient::exec_and_format(request, self.clone()).await?
+            };
+            if let Some(df) = result.data {
+                data_frame.vstack_mut(&df)?;
+            }
+            from = to + Duration::days(1);
+        }
+
+        let df = self
+            .filter_pair(data_frame)?
+            .lazy()
+            .sort_by_exprs([col("日期")], [true], false)
+            .collect()?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+}
+
+///
+/// 东方财富网-行情中心-外汇-离岸人民币
+/// http://quote.eastmoney.com/center/gridlist.html#forex_cnh
+///
+/// 包括 USDCNH、EURCNH、HKDCNH、JPYCNH 等，东方财富市场代码 133
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyCnhDataSource {}
+
+impl HttpSource for EastmoneyCnhDataSource {
+    fn request(&self) -> Request {
+        EastmoneyClist::request("m:133", "f2,f3,f4,f12,f14,f15,f16,f17,f18")
+    }
+}
+
+impl DataResultFormat for EastmoneyCnhDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyClist::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("f12", "代码"),
+            ("f14", "名称"),
+            ("f2", "最新价"),
+            ("f3", "涨跌幅"),
+            ("f4", "涨跌额"),
+            ("f15", "最高"),
+            ("f16", "最低"),
+            ("f17", "今开"),
+            ("f18", "昨收"),
+            ("f12", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "代码" | "名称" | "symbol" => DataType::Utf8,
+                _ => DataType::Float64,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyCnhDataSource {
+    ///
+    /// 离岸人民币全部货币对实时行情
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyCnhDataSource {
+    ///
+    /// 离岸人民币日K线，market 不区分传空即可，symbol 为货币对代码，如 USDCNH
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let secid = format!("133.{}", symbol.to_uppercase());
+
+        HttpClient::exec_by_cache(
+            EastmoneyKline::request(&secid, "0", start, end),
+            EastmoneyKline,
+        )
+        .await
+    }
+}
@@ -0,0 +1,2 @@
+/// 人民币汇率中间价及离岸人民币行情
+pub mod cny;
@@ -0,0 +1,384 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::{
+    header::{HeaderValue, CONTENT_LENGTH},
+    Method, Request, Url,
+};
+use serde_json::Value;
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyDataCenter,
+    utils::{DateUtils, HttpClient},
+    DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// SHIBOR 期限
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShiborTerm {
+    /// 隔夜
+    Overnight,
+    /// 1周
+    Week1,
+    /// 2周
+    Week2,
+    /// 1月
+    Month1,
+    /// 3月
+    Month3,
+    /// 6月
+    Month6,
+    /// 9月
+    Month9,
+    /// 1年
+    Year1,
+}
+
+impl ShiborTerm {
+    fn indicator_id(&self) -> &str {
+        match self {
+            ShiborTerm::Overnight => "001",
+            ShiborTerm::Week1 => "101",
+            ShiborTerm::Week2 => "102",
+            ShiborTerm::Month1 => "201",
+            ShiborTerm::Month3 => "203",
+            ShiborTerm::Month6 => "206",
+            ShiborTerm::Month9 => "209",
+            ShiborTerm::Year1 => "301",
+        }
+    }
+}
+
+///
+/// 统计期频率
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Frequency {
+    /// 日，统计期同日期
+    Day,
+    /// 月，统计期如 2023-10
+    Month,
+    /// 季，统计期如 2023Q3
+    Quarter,
+}
+
+///
+/// 中国宏观经济指标及利率
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacroIndicator {
+    /// 全国居民消费价格指数(CPI)同比，%
+    Cpi,
+    /// 工业生产者出厂价格指数(PPI)同比，%
+    Ppi,
+    /// 制造业采购经理指数(PMI)
+    ManufacturingPmi,
+    /// 非制造业商务活动指数
+    NonManufacturingPmi,
+    /// 国内生产总值(GDP)累计同比，%
+    Gdp,
+    /// 货币(M1)同比，%
+    M1,
+    /// 货币和准货币(M2)同比，%
+    M2,
+    /// 社会融资规模增量，亿元
+    SocialFinancing,
+    /// 上海银行间同业拆放利率，%
+    Shibor(ShiborTerm),
+    /// 1年期贷款市场报价利率，%
+    Lpr1Y,
+    /// 5年期以上贷款市场报价利率，%
+    Lpr5Y,
+}
+
+impl MacroIndicator {
+    ///
+    /// 数据中心报表名、过滤条件、日期列、数值列
+    ///
+    fn report(&self) -> (&str, String, &str, &str) {
+        match self {
+            MacroIndicator::Cpi => (
+                "RPT_ECONOMY_CPI",
+                "".to_string(),
+                "REPORT_DATE",
+                "NATIONAL_SAME",
+            ),
+            MacroIndicator::Ppi => (
+                "RPT_ECONOMY_PPI",
+                "".to_string(),
+                "REPORT_DATE",
+                "BASE_SAME",
+            ),
+            MacroIndicator::ManufacturingPmi => (
+                "RPT_ECONOMY_PMI",
+                "".to_string(),
+                "REPORT_DATE",
+                "MAKE_INDEX",
+            ),
+            MacroIndicator::NonManufacturingPmi => (
+                "RPT_ECONOMY_PMI",
+                "".to_string(),
+                "REPORT_DATE",
+                "NMAKE_INDEX",
+            ),
+            MacroIndicator::Gdp => ("RPT_ECONOMY_GDP", "".to_string(), "REPORT_DATE", "SUM_SAME"),
+            MacroIndicator::M1 => (
+                "RPT_ECONOMY_CURRENCY_SUPPLY",
+                "".to_string(),
+                "REPORT_DATE",
+                "CURRENCY_SAME",
+            ),
+            MacroIndicator::M2 => (
+                "RPT_ECONOMY_CURRENCY_SUPPLY",
+                "".to_string(),
+                "REPORT_DATE",
+                "BASIC_CURRENCY_SAME",
+            ),
+            MacroIndicator::Shibor(term) => (
+                "RPT_IMP_INTRESTRATEN",
+                format!(
+                    "(MARKET_CODE=\"001\")(CURRENCY_CODE=\"CNY\")(INDICATOR_ID=\"{}\")",
+                    term.indicator_id()
+                ),
+                "REPORT_DATE",
+                "IR_RATE",
+            ),
+            MacroIndicator::Lpr1Y => ("RPTA_WEB_RATE", "".to_string(), "TRADE_DATE", "LPR1Y"),
+            MacroIndicator::Lpr5Y => ("RPTA_WEB_RATE", "".to_string(), "TRADE_DATE", "LPR5Y"),
+            // 社会融资规模来自商务部数据中心，日期如 201501
+            MacroIndicator::SocialFinancing => ("", "".to_string(), "date", "tiosfs"),
+        }
+    }
+
+    fn frequency(&self) -> Frequency {
+        match self {
+            MacroIndicator::Gdp => Frequency::Quarter,
+            MacroIndicator::Shibor(_) | MacroIndicator::Lpr1Y | MacroIndicator::Lpr5Y => {
+                Frequency::Day
+            }
+            _ => Frequency::Month,
+        }
+    }
+}
+
+///
+/// 中国宏观经济指标: CPI、PPI、PMI、GDP、M1/M2、社会融资规模、SHIBOR、LPR
+/// 东方财富数据中心: https://data.eastmoney.com/cjsj/cpi.html
+/// 社会融资规模: 商务部数据中心 http://data.mofcom.gov.cn/gnmy/shrzgm.shtml
+///
+/// 统一返回 日期,数值,统计期 三列，月度、季度指标的日期为统计期首日，按日期倒序
+///
+#[derive(Clone, Debug)]
+pub struct MacroDataSource {
+    pub indicator: MacroIndicator,
+}
+
+impl MacroDataSource {
+    ///
+    /// 由日期生成统计期，2023-10-01 -> 2023-10 / 2023Q4
+    ///
+    fn period(&self, date: &str) -> String {
+        match self.indicator.frequency() {
+            Frequency::Day => date.to_string(),
+            Frequency::Month => date[..7].to_string(),
+            Frequency::Quarter => {
+                let month: u32 = date[5..7].parse().unwrap_or(1);
+                format!("{}Q{}", &date[..4], month.div_ceil(3))
+            }
+        }
+    }
+}
+
+impl MacroDataSource {
+    ///
+    /// 数据中心分页请求，date_filter 如 (REPORT_DATE>='2023-01-01')，与指标过滤条件合并
+    ///
+    fn request_page(&self, date_filter: &str, page: usize) -> Request {
+        let (report_name, filter, date_column, _) = self.indicator.report();
+        EastmoneyDataCenter::request_page(
+            report_name,
+            &format!("{}{}", filter, date_filter),
+            date_column,
+            "-1",
+            page,
+        )
+    }
+
+    ///
+    /// 获取全部分页并去掉未发布或已停止发布的数据，按日期倒序
+    ///
+    /// 社会融资规模接口一次返回全部数据，不分页也不支持按日期过滤
+    ///
+    async fn fetch(&self, date_filter: &str) -> anyhow::Result<DataFrame> {
+        let data_frame = match self.indicator {
+            MacroIndicator::SocialFinancing => {
+                HttpClient::exec_by_cache(self.request(), self.clone())
+                    .await?
+                    .data
+                    .unwrap_or_default()
+            }
+            _ => {
+                HttpClient::exec_by_pages(self, EastmoneyDataCenter::PAGE_SIZE, |page| {
+                    self.request_page(date_filter, page)
+                })
+                .await?
+            }
+        };
+        if data_frame.height() == 0 {
+            return Ok(self.col_schema().as_ref().map(DataFrame::from).unwrap());
+        }
+
+        let df = data_frame
+            .lazy()
+            .filter(col("日期").is_not_null().and(col("数值").is_not_null()))
+            .sort_by_exprs([col("日期")], [true], false)
+            .collect()?;
+
+        Ok(df)
+    }
+}
+
+impl HttpSource for MacroDataSource {
+    fn request(&self) -> Request {
+        match self.indicator {
+            // 商务部数据中心页面以无参数的 POST 查询全部数据，需显式声明空请求体
+            MacroIndicator::SocialFinancing => {
+                let url = Url::parse("http://data.mofcom.gov.cn/datamofcom/front/gnmy/shrzgmQuery")
+                    .unwrap();
+                let mut request = Request::new(Method::POST, url);
+                request
+                    .headers_mut()
+                    .insert(CONTENT_LENGTH, HeaderValue::from_static("0"));
+
+                request
+            }
+            _ => self.request_page("", 1),
+        }
+    }
+}
+
+impl DataResultFormat for MacroDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(&body)?;
+            let (_, _, date_column, value_column) = self.indicator.report();
+
+            let rows = match self.indicator {
+                MacroIndicator::SocialFinancing => json.as_array(),
+                _ => json
+                    .pointer("/result/data")
+                    .and_then(|rows| rows.as_array()),
+            };
+
+            // 保留未发布或已停止发布(如 2019-08 前的5年期LPR)的空值行，按整页判断翻页，合并后再去掉
+            let mut dates: Vec<Option<String>> = vec![];
+            let mut values: Vec<Option<f64>> = vec![];
+            for row in rows.into_iter().flatten() {
+                let date = row.get(date_column).and_then(|d| match d {
+                    Value::String(s) => DateUtils::normalize_ymd(s),
+                    Value::Number(n) => DateUtils::normalize_ymd(&n.to_string()),
+                    _ => None,
+                });
+                let value = row.get(value_column).and_then(|v| match v {
+                    Value::Number(n) => n.as_f64(),
+                    Value::String(s) => s.replace(',', "").parse().ok(),
+                    _ => None,
+                });
+                dates.push(date);
+                values.push(value);
+            }
+            if dates.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let periods: Vec<Option<String>> = dates
+                .iter()
+                .map(|d| d.as_deref().map(|d| self.period(d)))
+                .collect();
+            let df = DataFrame::new(vec![
+                Series::new("date", dates),
+                Series::new("value", values),
+                Series::new("period", periods),
+            ])?
+            .lazy()
+            .sort_by_exprs([col("date")], [true], false)
+            .collect()?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![("date", "日期"), ("value", "数值"), ("period", "统计期")];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("数值".to_string(), DataType::Float64);
+        schema.with_column("统计期".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for MacroDataSource {
+    ///
+    /// 指标全部历史数据，按日期倒序
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let df = self.fetch("").await?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for MacroDataSource {
+    ///
+    /// 日期在 [start, end] 内的指标数据，market、symbol 不区分传空即可
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        _symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let (start, end) = (
+            start.format("%Y-%m-%d").to_string(),
+            end.format("%Y-%m-%d").to_string(),
+        );
+        let (_, _, date_column, _) = self.indicator.report();
+        let date_filter = format!("({}>='{}')({}<='{}')", date_column, start, date_column, end);
+
+        // 数据中心按日期过滤分页获取，社会融资规模获取全部后过滤
+        let df = self
+            .fetch(&date_filter)
+            .await?
+            .lazy()
+            .filter(
+                col("日期")
+                    .gt_eq(lit(start))
+                    .and(col("日期").lt_eq(lit(end))),
+            )
+            .collect()?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+}
@@ -0,0 +1,2 @@
+/// 中国宏观经济指标及利率
+pub mod china;
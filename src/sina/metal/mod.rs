@@ -0,0 +1,2 @@
+/// 上海黄金交易所基准价
+pub mod sge;
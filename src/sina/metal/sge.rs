@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Duration, Local, NaiveDate},
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::{Method, Request, Url};
+use serde_json::Value;
+
+use crate::{
+    utils::HttpClient, DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 上海黄金交易所基准价品种
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SgeBenchmark {
+    /// 上海金，元/克
+    Gold,
+    /// 上海银，元/千克
+    Silver,
+}
+
+impl SgeBenchmark {
+    fn url(&self) -> &str {
+        match self {
+            SgeBenchmark::Gold => "https://www.sge.com.cn/graph/DayilyJzj",
+            SgeBenchmark::Silver => "https://www.sge.com.cn/graph/DayilyShsilverJzj",
+        }
+    }
+}
+
+///
+/// 上海黄金交易所-数据资讯-上海金、上海银基准价
+/// https://www.sge.com.cn/sjzx/jzj
+///
+/// 每个交易日发布早盘价、午盘价
+///
+#[derive(Clone, Debug)]
+pub struct SgeBenchmarkDataSource {
+    pub benchmark: SgeBenchmark,
+}
+
+impl SgeBenchmarkDataSource {
+    ///
+    /// 解析 [[毫秒时间戳, 价格], ...]，时间戳按北京时间转为日期
+    ///
+    fn parse_prices(json: &Value, key: &str) -> Vec<(String, f64)> {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        match json.get(key) {
+            Some(Value::Array(rows)) => rows
+                .iter()
+                .filter_map(|row| {
+                    let ts = row.get(0)?.as_i64()?;
+                    let price = row.get(1)?.as_f64()?;
+                    let days = (ts / 1000 + 8 * 3600).div_euclid(86400);
+                    let date = epoch + Duration::days(days);
+                    Some((date.format("%Y-%m-%d").to_string(), price))
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl HttpSource for SgeBenchmarkDataSource {
+    fn request(&self) -> Request {
+        Request::new(Method::POST, Url::parse(self.benchmark.url()).unwrap())
+    }
+}
+
+impl DataResultFormat for SgeBenchmarkDataSource {
+    ///
+    /// zp 为早盘价，wp 为午盘价，按日期合并
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(&body)?;
+
+            let morning: HashMap<String, f64> = SgeBenchmarkDataSource::parse_prices(&json, "zp")
+                .into_iter()
+                .collect();
+            let afternoon: HashMap<String, f64> = SgeBenchmarkDataSource::parse_prices(&json, "wp")
+                .into_iter()
+                .collect();
+
+            let mut dates: Vec<String> = morning.keys().chain(afternoon.keys()).cloned().collect();
+            dates.sort_by(|a, b| b.cmp(a));
+            dates.dedup();
+            if dates.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let df = DataFrame::new(vec![
+                Series::new(
+                    "zp",
+                    dates
+                        .iter()
+                        .map(|d| morning.get(d).copied())
+                        .collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "wp",
+                    dates
+                        .iter()
+                        .map(|d| afternoon.get(d).copied())
+                        .collect::<Vec<_>>(),
+                ),
+                Series::new("date", dates),
+            ])?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![("date", "日期"), ("zp", "早盘价"), ("wp", "午盘价")];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("早盘价".to_string(), DataType::Float64);
+        schema.with_column("午盘价".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for SgeBenchmarkDataSource {
+    ///
+    /// 最新一个交易日的基准价，当日午盘价发布前可能只有早盘价，不缓存
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let mut result = HttpClient::exec_and_format(self.request(), self.clone()).await?;
+        result.data = result.data.map(|df| df.head(Some(1)));
+
+        Ok(result)
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for SgeBenchmarkDataSource {
+    ///
+    /// [start, end] 期间基准价，market、symbol 不区分传空即可，按日期倒序
+    ///
+    /// 接口一次返回全部历史，今日之前的基准价已定稿，按当日缓存；包含今日时当日价格尚未定稿，不缓存
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        _symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let result = if end < Local::now().date_naive() {
+            HttpClient::exec_by_cache(self.request(), self.clone()).await?
+        } else {
+            HttpClient::exec_and_format(self.request(), self.clone()).await?
+        };
+        let Some(df) = result.data else {
+            return Ok(result);
+        };
+
+        let df = df
+            .lazy()
+            .filter(
+                col("日期")
+                    .gt_eq(lit(start.format("%Y-%m-%d").to_string()))
+                    .and(col("日期").lt_eq(lit(end.format("%Y-%m-%d").to_string()))),
+            )
+            .collect()?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+}
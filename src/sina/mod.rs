@@ -1 +1,8 @@
+pub mod bond;
+pub mod fund;
+pub mod futures;
+pub mod fx;
+pub mod macro_economy;
+pub mod metal;
+pub mod option;
 pub mod stock;
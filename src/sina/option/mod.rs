@@ -0,0 +1,5 @@
+/// 期权定价及希腊字母
+pub mod pricing;
+
+/// 新浪财经数据源
+pub mod sina;
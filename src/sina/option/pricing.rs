@@ -0,0 +1,358 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use anyhow::anyhow;
+use polars::{
+    export::chrono::NaiveDate,
+    prelude::{DataFrame, DataType, NamedFrom, Series},
+};
+
+///
+/// 期权类型
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptionType {
+    /// 看涨期权
+    Call,
+    /// 看跌期权
+    Put,
+}
+
+impl OptionType {
+    ///
+    /// 期权链 类型 列的取值: C 看涨, P 看跌
+    ///
+    pub fn from_flag(flag: &str) -> Option<OptionType> {
+        match flag {
+            "C" | "c" => Some(OptionType::Call),
+            "P" | "p" => Some(OptionType::Put),
+            _ => None,
+        }
+    }
+}
+
+///
+/// 定价模型
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PricingModel {
+    /// 现货期权，如 50ETF、300ETF 期权
+    BlackScholes,
+    /// 期货期权，标的价格为期货价格，如以股指期货定价的沪深300股指期权
+    Black76,
+}
+
+///
+/// 希腊字母，Vega 为波动率变动 1% 的价格变动，Theta 为每自然日的时间价值变动
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    pub rho: f64,
+}
+
+///
+/// 期权定价，按 Black-Scholes / Black-76 计算理论价格、隐含波动率及希腊字母
+///
+#[derive(Clone, Debug)]
+pub struct OptionPricing {
+    pub model: PricingModel,
+    /// 标的价格，Black-76 时为期货价格
+    pub underlying_price: f64,
+    /// 无风险利率，连续复利，如 0.02
+    pub rate: f64,
+    /// 定价日，剩余期限 = (到期日 - 定价日) / 365
+    pub today: NaiveDate,
+}
+
+impl OptionPricing {
+    /// 隐含波动率求解区间及精度
+    const VOL_MIN: f64 = 1e-4;
+    const VOL_MAX: f64 = 5.0;
+    const VOL_TOLERANCE: f64 = 1e-8;
+
+    /// with_greeks 增加的列
+    const GREEK_COLUMNS: [&'static str; 7] = [
+        "剩余期限",
+        "隐含波动率",
+        "Delta",
+        "Gamma",
+        "Vega",
+        "Theta",
+        "Rho",
+    ];
+
+    ///
+    /// 由同一到期月份的期权链按看涨看跌平价估计标的远期价格，F = K + (C - P)，
+    /// 取看涨看跌价差绝对值最小的行权价，未计折现，用于 Black-76 计算隐含波动率
+    ///
+    pub fn parity_forward(chain: &DataFrame) -> anyhow::Result<Option<f64>> {
+        let types = chain.column("类型")?.utf8()?.clone();
+        let strikes = chain.column("行权价")?.cast(&DataType::Float64)?;
+        let prices = chain.column("最新价")?.cast(&DataType::Float64)?;
+
+        let mut calls: Vec<(f64, f64)> = vec![];
+        let mut puts: Vec<(f64, f64)> = vec![];
+        for ((flag, strike), price) in types.into_iter().zip(strikes.f64()?).zip(prices.f64()?) {
+            let (Some(option_type), Some(strike), Some(price)) =
+                (flag.and_then(OptionType::from_flag), strike, price)
+            else {
+                continue;
+            };
+            if price <= 0.0 {
+                continue;
+            }
+            match option_type {
+                OptionType::Call => calls.push((strike, price)),
+                OptionType::Put => puts.push((strike, price)),
+            }
+        }
+
+        let forward = calls
+            .iter()
+            .filter_map(|(strike, call)| {
+                let (_, put) = puts.iter().find(|(k, _)| k == strike)?;
+                Some((strike, call - put))
+            })
+            .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+            .map(|(strike, diff)| strike + diff);
+
+        Ok(forward)
+    }
+
+    ///
+    /// 持有成本，Black-Scholes 为无风险利率，Black-76 为 0
+    ///
+    fn carry(&self) -> f64 {
+        match self.model {
+            PricingModel::BlackScholes => self.rate,
+            PricingModel::Black76 => 0.0,
+        }
+    }
+
+    fn d1_d2(&self, strike: f64, t: f64, sigma: f64) -> (f64, f64) {
+        let s = self.underlying_price;
+        let d1 =
+            ((s / strike).ln() + (self.carry() + sigma * sigma / 2.0) * t) / (sigma * t.sqrt());
+        (d1, d1 - sigma * t.sqrt())
+    }
+
+    ///
+    /// 理论价格，t 为剩余期限(年)
+    ///
+    pub fn price(&self, option_type: OptionType, strike: f64, t: f64, sigma: f64) -> f64 {
+        let s = self.underlying_price;
+        let r = self.rate;
+        let carry = ((self.carry() - r) * t).exp();
+        let discount = (-r * t).exp();
+
+        if t <= 0.0 || sigma <= 0.0 {
+            return match option_type {
+                OptionType::Call => (s * carry - strike * discount).max(0.0),
+                OptionType::Put => (strike * discount - s * carry).max(0.0),
+            };
+        }
+
+        let (d1, d2) = self.d1_d2(strike, t, sigma);
+        match option_type {
+            OptionType::Call => s * carry * norm_cdf(d1) - strike * discount * norm_cdf(d2),
+            OptionType::Put => strike * discount * norm_cdf(-d2) - s * carry * norm_cdf(-d1),
+        }
+    }
+
+    ///
+    /// 希腊字母，t 为剩余期限(年)
+    ///
+    pub fn greeks(&self, option_type: OptionType, strike: f64, t: f64, sigma: f64) -> Greeks {
+        if t <= 0.0 || sigma <= 0.0 {
+            return Greeks::default();
+        }
+
+        let s = self.underlying_price;
+        let r = self.rate;
+        let b = self.carry();
+        let carry = ((b - r) * t).exp();
+        let discount = (-r * t).exp();
+        let (d1, d2) = self.d1_d2(strike, t, sigma);
+        let pdf = norm_pdf(d1);
+
+        let gamma = carry * pdf / (s * sigma * t.sqrt());
+        let vega = s * carry * pdf * t.sqrt();
+        let decay = -s * carry * pdf * sigma / (2.0 * t.sqrt());
+
+        let (delta, theta) = match option_type {
+            OptionType::Call => (
+                carry * norm_cdf(d1),
+                decay - (b - r) * s * carry * norm_cdf(d1) - r * strike * discount * norm_cdf(d2),
+            ),
+            OptionType::Put => (
+                carry * (norm_cdf(d1) - 1.0),
+                decay + (b - r) * s * carry * norm_cdf(-d1) + r * strike * discount * norm_cdf(-d2),
+            ),
+        };
+
+        let rho = match (self.model, option_type) {
+            (PricingModel::Black76, _) => -t * self.price(option_type, strike, t, sigma),
+            (PricingModel::BlackScholes, OptionType::Call) => strike * t * discount * norm_cdf(d2),
+            (PricingModel::BlackScholes, OptionType::Put) => -strike * t * discount * norm_cdf(-d2),
+        };
+
+        Greeks {
+            delta,
+            gamma,
+            vega: vega / 100.0,
+            theta: theta / 365.0,
+            rho: rho / 100.0,
+        }
+    }
+
+    ///
+    /// 由期权价格二分法求隐含波动率，价格超出理论价格范围时返回 None
+    ///
+    pub fn implied_vol(
+        &self,
+        option_type: OptionType,
+        strike: f64,
+        t: f64,
+        price: f64,
+    ) -> Option<f64> {
+        if t <= 0.0 || price <= 0.0 {
+            return None;
+        }
+
+        let (mut low, mut high) = (OptionPricing::VOL_MIN, OptionPricing::VOL_MAX);
+        let price_at = |sigma: f64| self.price(option_type, strike, t, sigma);
+        if price < price_at(low) || price > price_at(high) {
+            return None;
+        }
+
+        // 期权价格随波动率单调递增
+        while high - low > OptionPricing::VOL_TOLERANCE {
+            let mid = (low + high) / 2.0;
+            if price_at(mid) < price {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        Some((low + high) / 2.0)
+    }
+
+    ///
+    /// 按期权链计算隐含波动率及希腊字母
+    ///
+    /// 期权链需包含 类型(C/P),行权价,最新价,到期日(年-月-日) 列，
+    /// 增加 剩余期限,隐含波动率,Delta,Gamma,Vega,Theta,Rho 列
+    ///
+    pub fn with_greeks(&self, chain: &DataFrame) -> anyhow::Result<DataFrame> {
+        // 已有的同名列(如期权链自带的隐含波动率)以本次计算结果为准
+        let mut chain = chain.clone();
+        for name in OptionPricing::GREEK_COLUMNS {
+            if chain.column(name).is_ok() {
+                chain = chain.drop(name)?;
+            }
+        }
+
+        let column = |name: &str| {
+            chain
+                .column(name)
+                .map_err(|_| anyhow!("期权链缺少列: {}", name))
+        };
+        let types = column("类型")?.utf8()?.clone();
+        let strikes = column("行权价")?.cast(&DataType::Float64)?;
+        let prices = column("最新价")?.cast(&DataType::Float64)?;
+        let expires = column("到期日")?.utf8()?.clone();
+
+        let mut terms: Vec<Option<f64>> = vec![];
+        let mut vols: Vec<Option<f64>> = vec![];
+        let mut greeks: Vec<Option<Greeks>> = vec![];
+        for (((flag, strike), price), expire) in types
+            .into_iter()
+            .zip(strikes.f64()?)
+            .zip(prices.f64()?)
+            .zip(&expires)
+        {
+            let t = expire
+                .and_then(|e| NaiveDate::parse_from_str(e, "%Y-%m-%d").ok())
+                .map(|e| (e - self.today).num_days() as f64 / 365.0);
+            let option_type = flag.and_then(OptionType::from_flag);
+
+            let vol = match (option_type, strike, price, t) {
+                (Some(o), Some(k), Some(p), Some(t)) => self.implied_vol(o, k, t, p),
+                _ => None,
+            };
+            let greek = match (option_type, strike, t, vol) {
+                (Some(o), Some(k), Some(t), Some(v)) => Some(self.greeks(o, k, t, v)),
+                _ => None,
+            };
+
+            terms.push(t);
+            vols.push(vol);
+            greeks.push(greek);
+        }
+
+        let greek_column = |name: &str, f: fn(&Greeks) -> f64| {
+            Series::new(
+                name,
+                greeks
+                    .iter()
+                    .map(|g| g.as_ref().map(f))
+                    .collect::<Vec<Option<f64>>>(),
+            )
+        };
+
+        let [term, vol, delta, gamma, vega, theta, rho] = OptionPricing::GREEK_COLUMNS;
+        let df = chain.hstack(&[
+            Series::new(term, terms),
+            Series::new(vol, vols),
+            greek_column(delta, |g| g.delta),
+            greek_column(gamma, |g| g.gamma),
+            greek_column(vega, |g| g.vega),
+            greek_column(theta, |g| g.theta),
+            greek_column(rho, |g| g.rho),
+        ])?;
+
+        Ok(df)
+    }
+}
+
+///
+/// 标准正态分布概率密度
+///
+fn norm_pdf(x: f64) -> f64 {
+    (-x * x / 2.0).exp() / (2.0 * PI).sqrt()
+}
+
+///
+/// 标准正态分布累积分布
+///
+fn norm_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+///
+/// 互补误差函数，Chebyshev 近似，相对误差小于 1.2e-7
+///
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let r = t
+        * (-z * z - 1.26551223
+            + t * (1.00002368
+                + t * (0.37409196
+                    + t * (0.09678418
+                        + t * (-0.18628806
+                            + t * (0.27886807
+                                + t * (-1.13520398
+                                    + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+            .exp();
+
+    if x >= 0.0 {
+        r
+    } else {
+        2.0 - r
+    }
+}
@@ -0,0 +1,537 @@
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Datelike, Local, NaiveDate, Weekday},
+    lazy::dsl::{col, lit},
+    prelude::{
+        DataFrame, DataType, IntoLazy, JsonFormat, JsonReader, NamedFrom, Schema, SerReader,
+        Series, NULL,
+    },
+};
+use reqwest::{Method, Request, Url};
+use serde_json::Value;
+use std::io::Cursor;
+
+use crate::{
+    sina::{
+        futures::contract::LastTradingDay,
+        option::pricing::{OptionPricing, PricingModel},
+        stock::sina::SinaHq,
+    },
+    utils::{HttpClient, JsonUtils},
+    DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 期权标的
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OptionUnderlying {
+    /// 上交所 50ETF 期权
+    Etf50,
+    /// 上交所 300ETF 期权
+    Etf300,
+    /// 中金所 沪深300股指期权
+    Io,
+}
+
+impl OptionUnderlying {
+    ///
+    /// 新浪期权代码前缀，到期月份代码为前缀加年月，如 5100502312、io2312
+    ///
+    fn code(&self) -> &str {
+        match self {
+            OptionUnderlying::Etf50 => "510050",
+            OptionUnderlying::Etf300 => "510300",
+            OptionUnderlying::Io => "io",
+        }
+    }
+
+    ///
+    /// 到期日规则: ETF期权为到期月份第四个星期三，股指期权为到期月份第三个星期五
+    ///
+    fn expiry_rule(&self) -> LastTradingDay {
+        match self {
+            OptionUnderlying::Io => LastTradingDay::NthWeekday(3, Weekday::Fri),
+            _ => LastTradingDay::NthWeekday(4, Weekday::Wed),
+        }
+    }
+
+    ///
+    /// 挂牌月份: ETF期权为当月、下月及随后两个季月，股指期权为当月、下两个月及随后三个季月
+    ///
+    fn listing(&self) -> (usize, usize) {
+        match self {
+            OptionUnderlying::Io => (3, 3),
+            _ => (2, 2),
+        }
+    }
+
+    ///
+    /// 到期月份的到期日，month 为四位年月，如 2312
+    ///
+    pub fn expiry(&self, month: &str) -> anyhow::Result<NaiveDate> {
+        let ym: u32 = month
+            .parse()
+            .map_err(|_| anyhow!("到期月份格式应为四位年月，如 2312: {}", month))?;
+        let (year, month) = (2000 + (ym / 100) as i32, ym % 100);
+        if !(1..=12).contains(&month) {
+            return Err(anyhow!("到期月份格式应为四位年月，如 2312: {}", ym));
+        }
+
+        Ok(self.expiry_rule().date(year, month))
+    }
+
+    ///
+    /// 指定日期挂牌的到期月份，按工作日推算，未考虑法定节假日顺延
+    /// :return: 到期月份,到期日,剩余天数
+    ///
+    pub fn expiries(&self, date: NaiveDate) -> anyhow::Result<DataFrame> {
+        let rule = self.expiry_rule();
+        let candidates: Vec<(i32, u32, NaiveDate)> = (0..13)
+            .map(|i| {
+                let months = date.year() * 12 + date.month0() as i32 + i;
+                let (year, month) = (months / 12, (months % 12) as u32 + 1);
+                (year, month, rule.date(year, month))
+            })
+            .filter(|(_, _, expiry)| *expiry >= date)
+            .collect();
+
+        let (near, quarters) = self.listing();
+        let mut expiries: Vec<&(i32, u32, NaiveDate)> = candidates.iter().take(near).collect();
+        expiries.extend(
+            candidates
+                .iter()
+                .skip(near)
+                .filter(|(_, month, _)| month % 3 == 0)
+                .take(quarters),
+        );
+
+        let df = DataFrame::new(vec![
+            Series::new(
+                "到期月份",
+                expiries
+                    .iter()
+                    .map(|(year, month, _)| format!("{:02}{:02}", year % 100, month))
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "到期日",
+                expiries
+                    .iter()
+                    .map(|(_, _, expiry)| expiry.format("%Y-%m-%d").to_string())
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "剩余天数",
+                expiries
+                    .iter()
+                    .map(|(_, _, expiry)| (*expiry - date).num_days())
+                    .collect::<Vec<_>>(),
+            ),
+        ])?;
+
+        Ok(df)
+    }
+}
+
+///
+/// 新浪财经-期权-上交所ETF期权、中金所沪深300股指期权-期权链实时行情
+/// https://stock.finance.sina.com.cn/option/quotes.html
+///
+#[derive(Clone, Debug)]
+pub struct SinaOptionChainDataSource {
+    pub underlying: OptionUnderlying,
+    /// 到期月份，四位年月，如 2312，可由 OptionUnderlying::expiries 获取
+    pub month: String,
+}
+
+impl SinaOptionChainDataSource {
+    ///
+    /// 行情字段: 代码,名称,类型(C/P),行权价,最新价,买价,卖价,买量,卖量,成交量,持仓量
+    ///
+    const FIELDS: [&'static str; 11] = [
+        "symbol",
+        "name",
+        "type",
+        "strike",
+        "last",
+        "bid",
+        "ask",
+        "bid_volume",
+        "ask_volume",
+        "volume",
+        "open_interest",
+    ];
+
+    ///
+    /// ETF期权行情 var hq_str_CON_OP_10006001="买量,买价,最新价,卖价,卖量,持仓量,涨幅,行权价,...";
+    /// 第37位为合约简称(如 50ETF购12月2400)，第41位为成交量
+    ///
+    fn etf_rows(body: &str) -> Vec<Vec<Option<String>>> {
+        let field = |values: &[&str], i: usize| {
+            values
+                .get(i)
+                .filter(|v| !v.is_empty())
+                .map(|v| v.to_string())
+        };
+
+        SinaHq::parse(body)
+            .into_iter()
+            .map(|(symbol, values)| {
+                let name = field(&values, 37);
+                let flag = match &name {
+                    Some(name) if name.contains('沽') => "P",
+                    _ => "C",
+                };
+
+                vec![
+                    Some(symbol.trim_start_matches("CON_OP_").to_string()),
+                    name,
+                    Some(flag.to_string()),
+                    field(&values, 7),
+                    field(&values, 2),
+                    field(&values, 1),
+                    field(&values, 3),
+                    field(&values, 0),
+                    field(&values, 4),
+                    field(&values, 41),
+                    field(&values, 5),
+                ]
+            })
+            .collect()
+    }
+
+    ///
+    /// 股指期权 result.data.up 为看涨: 买量,买价,最新价,卖价,卖量,持仓量,涨跌,行权价,代码；
+    /// result.data.down 为看跌: 买量,买价,最新价,卖价,卖量,持仓量,涨跌,代码，与看涨按行对应行权价
+    ///
+    fn io_rows(body: &str) -> anyhow::Result<Vec<Vec<Option<String>>>> {
+        let json: Value = serde_json::from_str(body)?;
+        let field = |row: &Value, i: usize| match row.get(i) {
+            Some(Value::String(v)) if !v.is_empty() => Some(v.to_string()),
+            Some(Value::Number(v)) => Some(v.to_string()),
+            _ => None,
+        };
+        let rows = |side: &str| match json.pointer(&format!("/result/data/{}", side)) {
+            Some(Value::Array(rows)) => rows.clone(),
+            _ => vec![],
+        };
+
+        let ups = rows("up");
+        let downs = rows("down");
+        let mut result = vec![];
+        for (i, up) in ups.iter().enumerate() {
+            let strike = field(up, 7);
+            let symbol = field(up, 8);
+            result.push(vec![
+                symbol.clone(),
+                symbol,
+                Some("C".to_string()),
+                strike.clone(),
+                field(up, 2),
+                field(up, 1),
+                field(up, 3),
+                field(up, 0),
+                field(up, 4),
+                None,
+                field(up, 5),
+            ]);
+
+            if let Some(down) = downs.get(i) {
+                let symbol = field(down, 7);
+                result.push(vec![
+                    symbol.clone(),
+                    symbol,
+                    Some("P".to_string()),
+                    strike,
+                    field(down, 2),
+                    field(down, 1),
+                    field(down, 3),
+                    field(down, 0),
+                    field(down, 4),
+                    None,
+                    field(down, 5),
+                ]);
+            }
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// ETF期权按到期月份查询看涨、看跌合约代码，请求失败时返回错误
+    /// var hq_str_OP_UP_5100502312="CON_OP_10006001,CON_OP_10006002,...";
+    ///
+    async fn etf_symbols(&self) -> anyhow::Result<Vec<String>> {
+        let body = HttpClient::exec_text(self.request()).await?;
+
+        Ok(SinaHq::parse(&body)
+            .into_iter()
+            .flat_map(|(_, values)| values)
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+            .collect())
+    }
+}
+
+impl HttpSource for SinaOptionChainDataSource {
+    ///
+    /// 股指期权为期权链请求，ETF期权为合约代码列表请求
+    ///
+    fn request(&self) -> Request {
+        let code = format!("{}{}", self.underlying.code(), self.month);
+        match self.underlying {
+            OptionUnderlying::Io => {
+                let url = Url::parse_with_params(
+                    "https://stock.finance.sina.com.cn/futures/api/openapi.php/OptionService.getOptionData",
+                    &[
+                        ("type", "futures"),
+                        ("product", "io"),
+                        ("exchange", "cffex"),
+                        ("pinzhong", &code),
+                    ],
+                )
+                .unwrap();
+
+                Request::new(Method::GET, url)
+            }
+            _ => SinaHq::request(&[format!("OP_UP_{}", code), format!("OP_DOWN_{}", code)]),
+        }
+    }
+}
+
+impl DataResultFormat for SinaOptionChainDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let rows = match self.underlying {
+                OptionUnderlying::Io => SinaOptionChainDataSource::io_rows(&body)?,
+                _ => SinaOptionChainDataSource::etf_rows(&body),
+            };
+            if rows.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let columns = SinaOptionChainDataSource::FIELDS
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let values: Vec<Option<&str>> = rows.iter().map(|r| r[i].as_deref()).collect();
+                    Series::new(field, values)
+                })
+                .collect();
+
+            return Ok(DataResult::new("".to_string(), DataFrame::new(columns)?));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("symbol", "symbol"),
+            ("name", "名称"),
+            ("type", "类型"),
+            ("strike", "行权价"),
+            ("last", "最新价"),
+            ("bid", "买价"),
+            ("ask", "卖价"),
+            ("bid_volume", "买量"),
+            ("ask_volume", "卖量"),
+            ("volume", "成交量"),
+            ("open_interest", "持仓量"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("类型".to_string(), DataType::Utf8);
+        schema.with_column("行权价".to_string(), DataType::Float64);
+        schema.with_column("最新价".to_string(), DataType::Float64);
+        schema.with_column("买价".to_string(), DataType::Float64);
+        schema.with_column("卖价".to_string(), DataType::Float64);
+        schema.with_column("买量".to_string(), DataType::Float64);
+        schema.with_column("卖量".to_string(), DataType::Float64);
+        schema.with_column("成交量".to_string(), DataType::Float64);
+        schema.with_column("持仓量".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for SinaOptionChainDataSource {
+    ///
+    /// 到期月份的全部期权合约实时行情，隐含波动率按看涨看跌平价估计的远期价格以 Black-76 计算
+    /// :return: symbol,名称,类型,行权价,最新价,买价,卖价,买量,卖量,成交量,持仓量,到期日,隐含波动率
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let mut result = match self.underlying {
+            OptionUnderlying::Io => HttpClient::exec_by_cache(self.request(), self.clone()).await?,
+            _ => {
+                let symbols = self.etf_symbols().await?;
+                if symbols.is_empty() {
+                    return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+                }
+                HttpClient::exec_by_cache(SinaHq::request(&symbols), self.clone()).await?
+            }
+        };
+
+        if let Some(df) = result.data.as_ref().filter(|df| df.height() > 0) {
+            let expiry = self.underlying.expiry(&self.month)?;
+            let df = df.hstack(&[Series::new(
+                "到期日",
+                vec![expiry.format("%Y-%m-%d").to_string(); df.height()],
+            )])?;
+
+            let df = match OptionPricing::parity_forward(&df)? {
+                Some(forward) => {
+                    let pricing = OptionPricing {
+                        model: PricingModel::Black76,
+                        underlying_price: forward,
+                        rate: 0.0,
+                        today: Local::now().date_naive(),
+                    };
+                    let names = df.get_column_names_owned();
+                    pricing
+                        .with_greeks(&df)?
+                        .lazy()
+                        .select(
+                            names
+                                .iter()
+                                .map(|n| col(n))
+                                .chain([col("隐含波动率")])
+                                .collect::<Vec<_>>(),
+                        )
+                        .collect()?
+                }
+                None => df
+                    .lazy()
+                    .with_column(lit(NULL).cast(DataType::Float64).alias("隐含波动率"))
+                    .collect()?,
+            };
+            result.data = Some(df);
+        }
+
+        Ok(result)
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+///
+/// 新浪财经-期权-单个合约日K线，上交所ETF期权代码如 10006001，股指期权代码如 io2312C3800
+///
+#[derive(Clone, Debug)]
+pub struct SinaOptionHistoryDataSource {}
+
+impl SinaOptionHistoryDataSource {
+    fn request(symbol: &str) -> Request {
+        let url = if symbol.to_lowercase().starts_with("io") {
+            Url::parse_with_params(
+                &format!(
+                    "https://stock.finance.sina.com.cn/futures/api/jsonp.php/var%20_{}=/FutureOptionAllService.getOptionDayline",
+                    symbol
+                ),
+                &[("symbol", symbol)],
+            )
+        } else {
+            Url::parse_with_params(
+                "https://stock.finance.sina.com.cn/futures/api/jsonp_v2.php//StockOptionDaylineService.getSymbolInfo",
+                &[("symbol", format!("CON_OP_{}", symbol))],
+            )
+        }
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+}
+
+impl DataResultFormat for SinaOptionHistoryDataSource {
+    ///
+    /// 解析 ([{"d":"2023-12-01","o":"0.1","h":"0.1","l":"0.1","c":"0.1","v":"100"}]);
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(JsonUtils::unwrap_jsonp(&body))?;
+
+            let df = match json {
+                Value::Array(rows) if !rows.is_empty() => {
+                    let file = Cursor::new(serde_json::to_string(&rows)?);
+                    JsonReader::new(file)
+                        .with_json_format(JsonFormat::Json)
+                        .finish()?
+                }
+                _ => DataFrame::empty(),
+            };
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("d", "日期"),
+            ("o", "开盘"),
+            ("h", "最高"),
+            ("l", "最低"),
+            ("c", "收盘"),
+            ("v", "成交量"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("开盘".to_string(), DataType::Float64);
+        schema.with_column("最高".to_string(), DataType::Float64);
+        schema.with_column("最低".to_string(), DataType::Float64);
+        schema.with_column("收盘".to_string(), DataType::Float64);
+        schema.with_column("成交量".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for SinaOptionHistoryDataSource {
+    ///
+    /// 期权合约日K线，market 不区分市场传空即可，symbol 为合约代码
+    /// :return: 代码,日期,开盘,最高,最低,收盘,成交量
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let result =
+            HttpClient::exec_by_cache(SinaOptionHistoryDataSource::request(symbol), self).await?;
+
+        let Some(df) = result.data.filter(|df| df.height() > 0) else {
+            return Ok(DataResult::new(symbol.to_string(), DataFrame::empty()));
+        };
+        let df = df
+            .lazy()
+            .filter(
+                col("日期")
+                    .gt_eq(lit(start.format("%Y-%m-%d").to_string()))
+                    .and(col("日期").lt_eq(lit(end.format("%Y-%m-%d").to_string()))),
+            )
+            .select([lit(symbol).alias("代码"), col("*")])
+            .collect()?;
+
+        Ok(DataResult::new(symbol.to_string(), df))
+    }
+}
@@ -0,0 +1,263 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    prelude::{DataFrame, DataType, Schema},
+};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyDataCenter, utils::HttpClient, DataResult, DataResultFormat,
+    HistoryData, HttpSource,
+};
+
+///
+/// 龙虎榜数据类型
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Billboard {
+    /// 上榜股票: 上榜原因、龙虎榜买入卖出及净买额
+    Stocks,
+    /// 买入金额最大的前5名营业部席位
+    BuySeats,
+    /// 卖出金额最大的前5名营业部席位
+    SellSeats,
+}
+
+impl Billboard {
+    fn report_name(&self) -> &str {
+        match self {
+            Billboard::Stocks => "RPT_DAILYBILLBOARD_DETAILSNEW",
+            Billboard::BuySeats => "RPT_BILLBOARD_DAILYDETAILSBUY",
+            Billboard::SellSeats => "RPT_BILLBOARD_DAILYDETAILSSELL",
+        }
+    }
+}
+
+///
+/// 东方财富数据中心-龙虎榜单-每日龙虎榜详情及营业部席位明细
+/// https://data.eastmoney.com/stock/tradedetail.html
+///
+/// 上榜股票与席位明细分别查询，两者可按 代码、日期 关联，金额单位为元
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyBillboardDataSource {
+    pub billboard: Billboard,
+}
+
+impl EastmoneyBillboardDataSource {
+    fn request_page(&self, filter: &str, page: usize) -> Request {
+        EastmoneyDataCenter::request_page(
+            self.billboard.report_name(),
+            filter,
+            "TRADE_DATE,SECURITY_CODE",
+            "-1,1",
+            page,
+        )
+    }
+}
+
+impl HttpSource for EastmoneyBillboardDataSource {
+    fn request(&self) -> Request {
+        self.request_page("", 1)
+    }
+}
+
+impl DataResultFormat for EastmoneyBillboardDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let mut ca = vec![
+            ("SECURITY_CODE", "代码"),
+            ("SECURITY_NAME_ABBR", "名称"),
+            ("TRADE_DATE", "日期"),
+        ];
+
+        match self.billboard {
+            Billboard::Stocks => ca.extend([
+                ("EXPLAIN", "解读"),
+                ("CLOSE_PRICE", "收盘价"),
+                ("CHANGE_RATE", "涨跌幅"),
+                ("BILLBOARD_NET_AMT", "龙虎榜净买额"),
+                ("BILLBOARD_BUY_AMT", "龙虎榜买入额"),
+                ("BILLBOARD_SELL_AMT", "龙虎榜卖出额"),
+                ("BILLBOARD_DEAL_AMT", "龙虎榜成交额"),
+                ("ACCUM_AMOUNT", "市场总成交额"),
+                ("DEAL_NET_RATIO", "净买额占总成交比"),
+                ("DEAL_AMOUNT_RATIO", "成交额占总成交比"),
+                ("TURNOVERRATE", "换手率"),
+                ("FREE_MARKET_CAP", "流通市值"),
+                ("EXPLANATION", "上榜原因"),
+            ]),
+            Billboard::BuySeats | Billboard::SellSeats => ca.extend([
+                ("OPERATEDEPT_CODE", "营业部代码"),
+                ("OPERATEDEPT_NAME", "营业部名称"),
+                ("BUY", "买入金额"),
+                ("TOTAL_BUYRIO", "买入金额占总成交比"),
+                ("SELL", "卖出金额"),
+                ("TOTAL_SELLRIO", "卖出金额占总成交比"),
+                ("NET", "净额"),
+                ("EXPLANATION", "上榜原因"),
+            ]),
+        }
+        ca.push(("SECURITY_CODE", "symbol"));
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "代码" | "名称" | "日期" | "解读" | "上榜原因" | "营业部代码" | "营业部名称"
+                | "symbol" => DataType::Utf8,
+                _ => DataType::Float64,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyBillboardDataSource {
+    ///
+    /// [start, end] 期间的龙虎榜，market 不区分传空即可，symbol 为股票代码，传空时返回全部上榜股票
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let filter = date_filter(symbol, start, end);
+        let df = HttpClient::exec_by_pages(&self, EastmoneyDataCenter::PAGE_SIZE, |page| {
+            self.request_page(&filter, page)
+        })
+        .await?;
+
+        Ok(DataResult::new(symbol.to_string(), df))
+    }
+}
+
+///
+/// 东方财富数据中心-大宗交易-每日明细
+/// https://data.eastmoney.com/dzjy/dzjy_mrmxa.html
+///
+/// 成交量单位为股，成交额单位为元，折溢率为成交价相对当日收盘价
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyBlockTradeDataSource {}
+
+impl EastmoneyBlockTradeDataSource {
+    fn request_page(&self, filter: &str, page: usize) -> Request {
+        EastmoneyDataCenter::request_page(
+            "RPT_DATA_BLOCKTRADE",
+            &format!("(SECURITY_TYPE_WEB=1){}", filter),
+            "TRADE_DATE,SECURITY_CODE,DEAL_AMT",
+            "-1,1,-1",
+            page,
+        )
+    }
+}
+
+impl HttpSource for EastmoneyBlockTradeDataSource {
+    fn request(&self) -> Request {
+        self.request_page("", 1)
+    }
+}
+
+impl DataResultFormat for EastmoneyBlockTradeDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("SECURITY_CODE", "代码"),
+            ("SECURITY_NAME_ABBR", "名称"),
+            ("TRADE_DATE", "日期"),
+            ("CHANGE_RATE", "涨跌幅"),
+            ("CLOSE_PRICE", "收盘价"),
+            ("DEAL_PRICE", "成交价"),
+            ("PREMIUM_RATIO", "折溢率"),
+            ("DEAL_VOLUME", "成交量"),
+            ("DEAL_AMT", "成交额"),
+            ("TURNOVER_RATE", "成交额占流通市值比"),
+            ("BUYER_NAME", "买方营业部"),
+            ("SELLER_NAME", "卖方营业部"),
+            ("SECURITY_CODE", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "代码" | "名称" | "日期" | "买方营业部" | "卖方营业部" | "symbol" => {
+                    DataType::Utf8
+                }
+                _ => DataType::Float64,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyBlockTradeDataSource {
+    ///
+    /// [start, end] 期间的A股大宗交易明细，market 不区分传空即可，symbol 为股票代码，传空时返回全部
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let filter = date_filter(symbol, start, end);
+        let df = HttpClient::exec_by_pages(&self, EastmoneyDataCenter::PAGE_SIZE, |page| {
+            self.request_page(&filter, page)
+        })
+        .await?;
+
+        Ok(DataResult::new(symbol.to_string(), df))
+    }
+}
+
+///
+/// 股票代码及交易日期过滤条件，symbol 可带市场前缀，传空时不按代码过滤
+///
+fn date_filter(symbol: &str, start: NaiveDate, end: NaiveDate) -> String {
+    let code = symbol.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+    let security = match code {
+        "" => "".to_string(),
+        code => format!("(SECURITY_CODE=\"{}\")", code),
+    };
+
+    format!(
+        "{}(TRADE_DATE>='{}')(TRADE_DATE<='{}')",
+        security,
+        start.format("%Y-%m-%d"),
+        end.format("%Y-%m-%d")
+    )
+}
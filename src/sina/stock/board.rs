@@ -0,0 +1,329 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    lazy::dsl::lit,
+    prelude::{DataFrame, DataType, IntoLazy, Schema},
+};
+use reqwest::{Method, Request, Url};
+
+use crate::{
+    sina::stock::eastmoney::{EastmoneyClist, EastmoneyKline},
+    utils::HttpClient,
+    DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 板块类型
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BoardType {
+    /// 行业板块
+    Industry,
+    /// 概念板块
+    Concept,
+}
+
+impl BoardType {
+    fn fs(&self) -> &str {
+        match self {
+            BoardType::Industry => "m:90 t:2 f:!50",
+            BoardType::Concept => "m:90 t:3 f:!50",
+        }
+    }
+}
+
+///
+/// 东方财富网-行情中心-行业板块/概念板块-实时行情
+/// http://quote.eastmoney.com/center/boardlist.html#industry_board
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyBoardSpotDataSource {
+    pub board_type: BoardType,
+}
+
+impl HttpSource for EastmoneyBoardSpotDataSource {
+    fn request(&self) -> Request {
+        EastmoneyClist::request(
+            self.board_type.fs(),
+            "f2,f3,f4,f8,f12,f14,f15,f16,f17,f18,f20,f21,f24,f25,f22,f33,f11,f62,f128,f136,f115,f152,f124,f107,f104,f105,f140,f141,f207,f208,f209,f222",
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyBoardSpotDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyClist::to_dataframe(&body)?;
+            return Ok(DataResult::<DataFrame>::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("f12", "板块代码"),
+            ("f14", "板块名称"),
+            ("f2", "最新价"),
+            ("f3", "涨跌幅"),
+            ("f4", "涨跌额"),
+            ("f20", "总市值"),
+            ("f8", "换手率"),
+            ("f104", "上涨家数"),
+            ("f105", "下跌家数"),
+            ("f140", "领涨股票代码"),
+            ("f128", "领涨股票"),
+            ("f136", "领涨股票-涨跌幅"),
+            ("f12", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("板块代码".to_string(), DataType::Utf8);
+        schema.with_column("板块名称".to_string(), DataType::Utf8);
+        schema.with_column("最新价".to_string(), DataType::Float64);
+        schema.with_column("涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("涨跌额".to_string(), DataType::Float64);
+        schema.with_column("总市值".to_string(), DataType::Float64);
+        schema.with_column("换手率".to_string(), DataType::Float64);
+        schema.with_column("上涨家数".to_string(), DataType::Int64);
+        schema.with_column("下跌家数".to_string(), DataType::Int64);
+        schema.with_column("领涨股票代码".to_string(), DataType::Utf8);
+        schema.with_column("领涨股票".to_string(), DataType::Utf8);
+        schema.with_column("领涨股票-涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyBoardSpotDataSource {
+    ///
+    /// 所有行业板块或概念板块的实时行情
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyBoardSpotDataSource {
+    ///
+    /// 板块日K线，market 为东方财富板块市场 90，symbol 为板块代码，如 BK0475
+    ///
+    async fn history_daily(
+        self,
+        market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let secid = format!("{}.{}", market, symbol);
+
+        HttpClient::exec_by_cache(
+            EastmoneyKline::request(&secid, "0", start, end),
+            EastmoneyKline,
+        )
+        .await
+    }
+}
+
+///
+/// 东方财富网-行情中心-板块成分股-实时行情
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyBoardConsDataSource {
+    /// 板块代码，如 BK0475
+    pub board: String,
+}
+
+impl HttpSource for EastmoneyBoardConsDataSource {
+    fn request(&self) -> Request {
+        EastmoneyClist::request(
+            &format!("b:{} f:!50", self.board),
+            "f2,f3,f4,f5,f6,f7,f8,f9,f10,f12,f13,f14,f15,f16,f17,f18,f20,f21,f23",
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyBoardConsDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyClist::to_dataframe(&body)?;
+            if df.height() == 0 {
+                return Ok(DataResult::new("".to_string(), df));
+            }
+
+            // 增加板块代码列
+            let df = df
+                .lazy()
+                .with_column(lit(self.board.as_str()).alias("board"))
+                .collect()?;
+
+            return Ok(DataResult::<DataFrame>::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("board", "板块代码"),
+            ("f12", "代码"),
+            ("f14", "名称"),
+            ("f2", "最新价"),
+            ("f3", "涨跌幅"),
+            ("f4", "涨跌额"),
+            ("f5", "成交量"),
+            ("f6", "成交额"),
+            ("f7", "振幅"),
+            ("f15", "最高"),
+            ("f16", "最低"),
+            ("f17", "今开"),
+            ("f18", "昨收"),
+            ("f8", "换手率"),
+            ("f9", "市盈率-动态"),
+            ("f23", "市净率"),
+            ("f20", "总市值"),
+            ("f21", "流通市值"),
+            ("f12", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("板块代码".to_string(), DataType::Utf8);
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("最新价".to_string(), DataType::Float64);
+        schema.with_column("涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("涨跌额".to_string(), DataType::Float64);
+        schema.with_column("成交量".to_string(), DataType::Float64);
+        schema.with_column("成交额".to_string(), DataType::Float64);
+        schema.with_column("振幅".to_string(), DataType::Float64);
+        schema.with_column("最高".to_string(), DataType::Float64);
+        schema.with_column("最低".to_string(), DataType::Float64);
+        schema.with_column("今开".to_string(), DataType::Float64);
+        schema.with_column("昨收".to_string(), DataType::Float64);
+        schema.with_column("换手率".to_string(), DataType::Float64);
+        schema.with_column("市盈率-动态".to_string(), DataType::Float64);
+        schema.with_column("市净率".to_string(), DataType::Float64);
+        schema.with_column("总市值".to_string(), DataType::Float64);
+        schema.with_column("流通市值".to_string(), DataType::Float64);
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyBoardConsDataSource {
+    ///
+    /// 板块内所有成分股的实时行情
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+///
+/// 东方财富网-个股所属板块(行业、概念、地域)，用于由个股反查板块
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyStockBoardDataSource {
+    /// 股票代码，如 600519 或 sh600519
+    pub symbol: String,
+}
+
+impl HttpSource for EastmoneyStockBoardDataSource {
+    fn request(&self) -> Request {
+        let url = Url::parse_with_params(
+            "http://push2.eastmoney.com/api/qt/slist/get",
+            &[
+                ("spt", "3"),
+                ("secid", &EastmoneyClist::secid(&self.symbol)),
+                ("pi", "0"),
+                ("pz", "1000"),
+                ("po", "1"),
+                ("np", "1"),
+                ("fid", "f3"),
+                ("ut", "fa5fd1943c7b386f172d6893dbfba10b"),
+                ("fields", "f3,f12,f14"),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+}
+
+impl DataResultFormat for EastmoneyStockBoardDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyClist::to_dataframe(&body)?;
+            if df.height() == 0 {
+                return Ok(DataResult::new("".to_string(), df));
+            }
+
+            // 增加个股symbol列，用于关联行情
+            let df = df
+                .lazy()
+                .with_column(lit(self.symbol.as_str()).alias("symbol"))
+                .collect()?;
+
+            return Ok(DataResult::<DataFrame>::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("symbol", "symbol"),
+            ("f12", "板块代码"),
+            ("f14", "板块名称"),
+            ("f3", "板块涨跌幅"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+        schema.with_column("板块代码".to_string(), DataType::Utf8);
+        schema.with_column("板块名称".to_string(), DataType::Utf8);
+        schema.with_column("板块涨跌幅".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyStockBoardDataSource {
+    ///
+    /// 个股所属的全部板块
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
@@ -0,0 +1,376 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Datelike, Local, NaiveDate},
+    prelude::{DataFrame, DataType, NamedFrom, Schema, Series},
+};
+use reqwest::{Method, Request, Url};
+use serde_json::Value;
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyDataCenter, utils::HttpClient, DataResult, DataResultFormat,
+    HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 沪深港通资金通道
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConnectChannel {
+    /// 北向资金(沪股通 + 深股通)
+    Northbound,
+    /// 南向资金(港股通(沪) + 港股通(深))
+    Southbound,
+    /// 沪股通
+    Shanghai,
+    /// 深股通
+    Shenzhen,
+    /// 港股通(沪)
+    HkShanghai,
+    /// 港股通(深)
+    HkShenzhen,
+}
+
+impl ConnectChannel {
+    fn mutual_type(&self) -> &str {
+        match self {
+            ConnectChannel::Shanghai => "001",
+            ConnectChannel::HkShanghai => "002",
+            ConnectChannel::Shenzhen => "003",
+            ConnectChannel::HkShenzhen => "004",
+            ConnectChannel::Northbound => "005",
+            ConnectChannel::Southbound => "006",
+        }
+    }
+}
+
+///
+/// 东方财富数据中心-沪深港通资金流向-历史数据(日)
+/// https://data.eastmoney.com/hsgt/index.html
+///
+/// 金额单位为百万元，2024年8月起交易所不再披露北向资金每日成交净买额
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyConnectFlowDataSource {
+    pub channel: ConnectChannel,
+}
+
+impl EastmoneyConnectFlowDataSource {
+    fn request_by_date_filter(&self, date_filter: &str) -> Request {
+        let filter = format!(
+            "(MUTUAL_TYPE=\"{}\"){}",
+            self.channel.mutual_type(),
+            date_filter
+        );
+        EastmoneyDataCenter::request("RPT_MUTUAL_DEAL_HISTORY", &filter, "TRADE_DATE", "-1")
+    }
+}
+
+impl HttpSource for EastmoneyConnectFlowDataSource {
+    fn request(&self) -> Request {
+        self.request_by_date_filter("")
+    }
+}
+
+impl DataResultFormat for EastmoneyConnectFlowDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("TRADE_DATE", "日期"),
+            ("NET_DEAL_AMT", "成交净买额"),
+            ("BUY_AMT", "买入成交额"),
+            ("SELL_AMT", "卖出成交额"),
+            ("ACCUM_DEAL_AMT", "历史累计净买额"),
+            ("FUND_INFLOW", "当日资金流入"),
+            ("QUOTA_BALANCE", "当日余额"),
+            ("LEAD_STOCKS_NAME", "领涨股"),
+            ("LS_CHANGE_RATE", "领涨股-涨跌幅"),
+            ("INDEX_CLOSE_PRICE", "指数收盘"),
+            ("INDEX_CHANGE_RATE", "指数涨跌幅"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("成交净买额".to_string(), DataType::Float64);
+        schema.with_column("买入成交额".to_string(), DataType::Float64);
+        schema.with_column("卖出成交额".to_string(), DataType::Float64);
+        schema.with_column("历史累计净买额".to_string(), DataType::Float64);
+        schema.with_column("当日资金流入".to_string(), DataType::Float64);
+        schema.with_column("当日余额".to_string(), DataType::Float64);
+        schema.with_column("领涨股".to_string(), DataType::Utf8);
+        schema.with_column("领涨股-涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("指数收盘".to_string(), DataType::Float64);
+        schema.with_column("指数涨跌幅".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyConnectFlowDataSource {
+    ///
+    /// 资金通道全部历史日数据，按日期倒序
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyConnectFlowDataSource {
+    ///
+    /// [start, end] 期间资金通道日数据，market、symbol 不区分传空即可
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        _symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let date_filter = format!(
+            "(TRADE_DATE>='{}')(TRADE_DATE<='{}')",
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        );
+
+        HttpClient::exec_by_cache(self.request_by_date_filter(&date_filter), self).await
+    }
+}
+
+///
+/// 资金流向方向
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConnectDirection {
+    /// 北向: 沪股通、深股通
+    Northbound,
+    /// 南向: 港股通(沪)、港股通(深)
+    Southbound,
+}
+
+///
+/// 东方财富网-沪深港通-当日分时资金流向
+/// https://data.eastmoney.com/hsgt/index.html
+///
+/// 沪市、深市分别为沪股通/深股通或港股通(沪)/港股通(深)，金额单位为万元
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyConnectMinuteDataSource {
+    pub direction: ConnectDirection,
+}
+
+impl EastmoneyConnectMinuteDataSource {
+    ///
+    /// 补全接口返回的 月-日 日期，晚于今天的视为去年
+    ///
+    fn full_date(month_day: &str) -> Option<String> {
+        let today = Local::now().date_naive();
+        let date = |year: i32| {
+            NaiveDate::parse_from_str(&format!("{}-{}", year, month_day), "%Y-%m-%d").ok()
+        };
+        let date = date(today.year())
+            .filter(|d| *d <= today)
+            .or_else(|| date(today.year() - 1))?;
+
+        Some(date.format("%Y-%m-%d").to_string())
+    }
+}
+
+impl HttpSource for EastmoneyConnectMinuteDataSource {
+    fn request(&self) -> Request {
+        let url = Url::parse_with_params(
+            "http://push2.eastmoney.com/api/qt/kamtbs.rtmin/get",
+            &[
+                ("fields1", "f1,f2,f3,f4"),
+                ("fields2", "f51,f52,f54,f56"),
+                ("ut", "b2884a393a59ad64002292a3e90d46a5"),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+}
+
+impl DataResultFormat for EastmoneyConnectMinuteDataSource {
+    ///
+    /// 解析 data.s2n(北向)或 data.n2s(南向)，每分钟为 "时间,沪市,深市,合计"，未开盘的分钟为 "-"
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(&body)?;
+            let (rows, date) = match self.direction {
+                ConnectDirection::Northbound => ("/data/s2n", "/data/s2nDate"),
+                ConnectDirection::Southbound => ("/data/n2s", "/data/n2sDate"),
+            };
+
+            let rows: Vec<Vec<&str>> = match json.pointer(rows) {
+                Some(Value::Array(rows)) => rows
+                    .iter()
+                    .filter_map(|r| r.as_str())
+                    .map(|r| r.split(',').collect())
+                    .collect(),
+                _ => vec![],
+            };
+            if rows.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let date = json
+                .pointer(date)
+                .and_then(|d| d.as_str())
+                .and_then(EastmoneyConnectMinuteDataSource::full_date);
+            let value = |r: &Vec<&str>, i: usize| r.get(i).and_then(|v| v.parse::<f64>().ok());
+
+            let df = DataFrame::new(vec![
+                Series::new("date", vec![date; rows.len()]),
+                Series::new("time", rows.iter().map(|r| r[0]).collect::<Vec<_>>()),
+                Series::new("sh", rows.iter().map(|r| value(r, 1)).collect::<Vec<_>>()),
+                Series::new("sz", rows.iter().map(|r| value(r, 2)).collect::<Vec<_>>()),
+                Series::new(
+                    "total",
+                    rows.iter().map(|r| value(r, 3)).collect::<Vec<_>>(),
+                ),
+            ])?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("date", "日期"),
+            ("time", "时间"),
+            ("sh", "沪市"),
+            ("sz", "深市"),
+            ("total", "合计"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("时间".to_string(), DataType::Utf8);
+        schema.with_column("沪市".to_string(), DataType::Float64);
+        schema.with_column("深市".to_string(), DataType::Float64);
+        schema.with_column("合计".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyConnectMinuteDataSource {
+    ///
+    /// 最近交易日的分时资金流向，盘中持续更新，不缓存
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_and_format(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+///
+/// 东方财富数据中心-沪深港通持股-个股北向持股历史
+/// https://data.eastmoney.com/hsgt/StockHdDetail/600519.html
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyConnectHoldingDataSource {}
+
+impl DataResultFormat for EastmoneyConnectHoldingDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("TRADE_DATE", "持股日期"),
+            ("SECURITY_CODE", "代码"),
+            ("SECURITY_NAME", "名称"),
+            ("CLOSE_PRICE", "收盘价"),
+            ("CHANGE_RATE", "涨跌幅"),
+            ("HOLD_SHARES", "持股数量"),
+            ("HOLD_MARKET_CAP", "持股市值"),
+            ("FREE_SHARES_RATIO", "持股占流通股比"),
+            ("TOTAL_SHARES_RATIO", "持股占总股本比"),
+            ("SECURITY_CODE", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("持股日期".to_string(), DataType::Utf8);
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("收盘价".to_string(), DataType::Float64);
+        schema.with_column("涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("持股数量".to_string(), DataType::Float64);
+        schema.with_column("持股市值".to_string(), DataType::Float64);
+        schema.with_column("持股占流通股比".to_string(), DataType::Float64);
+        schema.with_column("持股占总股本比".to_string(), DataType::Float64);
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyConnectHoldingDataSource {
+    ///
+    /// 个股 [start, end] 期间每日北向持股，market 可传空，symbol 为A股代码，如 600519 或 sh600519
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let code = symbol.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+        let filter = format!(
+            "(SECURITY_CODE=\"{}\")(TRADE_DATE>='{}')(TRADE_DATE<='{}')",
+            code,
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        );
+        let request = EastmoneyDataCenter::request(
+            "RPT_MUTUAL_HOLDSTOCKNORTH_STA",
+            &filter,
+            "TRADE_DATE",
+            "-1",
+        );
+
+        HttpClient::exec_by_cache(request, self).await
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::prelude::{DataFrame, DataType, NamedFrom, Schema, Series};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::eastmoney::{EastmoneyClist, EastmoneyDataCenter},
+    utils::HttpClient,
+    DataResult, DataResultFormat, HttpSource, RealTimeData,
+};
+
+///
+/// 公司行为类型
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CorporateAction {
+    /// 分红送转: 现金分红、送股、转增
+    Dividend,
+    /// 配股
+    RightsIssue,
+    /// 股本变动: 总股本、流通股本
+    ShareChange,
+}
+
+impl CorporateAction {
+    fn report_name(&self) -> &str {
+        match self {
+            CorporateAction::Dividend => "RPT_SHAREBONUS_DET",
+            CorporateAction::RightsIssue => "RPT_IPO_ALLOTMENT",
+            CorporateAction::ShareChange => "RPT_F10_EH_EQUITY",
+        }
+    }
+
+    fn sort_column(&self) -> &str {
+        match self {
+            CorporateAction::Dividend => "REPORT_DATE",
+            CorporateAction::RightsIssue => "EQUITY_RECORD_DATE",
+            CorporateAction::ShareChange => "END_DATE",
+        }
+    }
+}
+
+///
+/// 东方财富数据中心-个股分红送配、配股、股本变动历史
+/// https://data.eastmoney.com/yjfp/detail/600519.html
+///
+/// 分红送转比例均为每10股，如 现金分红 259.11 表示每10股派 259.11 元(含税)
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyCorporateActionDataSource {
+    pub action: CorporateAction,
+    /// 股票代码，如 600519 或 sh600519
+    pub symbol: String,
+}
+
+impl EastmoneyCorporateActionDataSource {
+    fn filter(&self) -> String {
+        let secid = EastmoneyClist::secid(&self.symbol);
+        let (market, code) = secid.split_once('.').unwrap_or(("0", &self.symbol));
+        match self.action {
+            // 股本变动按带交易所后缀的证券代码查询，如 600519.SH
+            CorporateAction::ShareChange => {
+                let exchange = match (market, code.starts_with(['4', '8'])) {
+                    ("1", _) => "SH",
+                    (_, true) => "BJ",
+                    _ => "SZ",
+                };
+                format!("(SECUCODE=\"{}.{}\")", code, exchange)
+            }
+            _ => format!("(SECURITY_CODE=\"{}\")", code),
+        }
+    }
+
+    ///
+    /// 由分红送转、配股与不复权日收盘价计算除权除息复权因子
+    ///
+    /// dividends 为 CorporateAction::Dividend 的结果，rights 为 CorporateAction::RightsIssue 的结果，
+    /// closes 需包含 日期,收盘 列，同一除权日的分红送转与配股合并计算，
+    /// 除权参考价 = (前收盘 - 每股现金分红 + 配股价 * 每股配股比例) / (1 + 每股送转比例 + 每股配股比例)，
+    /// 复权因子 = 前收盘 / 除权参考价，累计复权因子为除权日及之前各次复权因子之积，用于计算后复权价格
+    /// :return: 除权除息日,前收盘,除权参考价,复权因子,累计复权因子
+    ///
+    pub fn adjust_factors(
+        dividends: &DataFrame,
+        rights: &DataFrame,
+        closes: &DataFrame,
+    ) -> anyhow::Result<DataFrame> {
+        let f64_column = |df: &DataFrame, name: &str| -> anyhow::Result<Vec<Option<f64>>> {
+            let column = df.column(name)?.cast(&DataType::Float64)?;
+            Ok(column.f64()?.into_iter().collect())
+        };
+
+        let dates = closes.column("日期")?.utf8()?.clone();
+        let prices = f64_column(closes, "收盘")?;
+        let mut closes: Vec<(&str, f64)> = dates
+            .into_iter()
+            .zip(prices)
+            .filter_map(|(d, p)| Some((d?, p?)))
+            .collect();
+        closes.sort_by(|a, b| a.0.cmp(b.0));
+
+        // 除权日 -> (每股现金分红, 每股送转比例, 每股配股比例, 每股配股缴款)，比例均由每10股换算
+        let mut events: BTreeMap<&str, (f64, f64, f64, f64)> = BTreeMap::new();
+
+        let ex_dates = dividends.column("除权除息日")?.utf8()?;
+        let cash = f64_column(dividends, "现金分红")?;
+        let bonus = f64_column(dividends, "送转总比例")?;
+        for (d, (c, b)) in ex_dates.into_iter().zip(cash.iter().zip(&bonus)) {
+            let (c, b) = (c.unwrap_or(0.0) / 10.0, b.unwrap_or(0.0) / 10.0);
+            if let Some(d) = d.filter(|_| c > 0.0 || b > 0.0) {
+                let event = events.entry(d).or_default();
+                event.0 += c;
+                event.1 += b;
+            }
+        }
+
+        let ex_dates = rights.column("除权日")?.utf8()?;
+        let ratio = f64_column(rights, "配股比例")?;
+        let price = f64_column(rights, "配股价")?;
+        for (d, (r, p)) in ex_dates.into_iter().zip(ratio.iter().zip(&price)) {
+            if let (Some(d), Some(r), Some(p)) = (d, r, p) {
+                if *r > 0.0 {
+                    let event = events.entry(d).or_default();
+                    event.2 += r / 10.0;
+                    event.3 += p * r / 10.0;
+                }
+            }
+        }
+
+        let mut rows: Vec<(&str, f64, f64, f64)> = vec![];
+        for (ex_date, (cash, bonus, ratio, payment)) in events {
+            // 除权除息日前一交易日收盘价
+            let Some((_, pre_close)) = closes.iter().rev().find(|(d, _)| *d < ex_date) else {
+                continue;
+            };
+            let reference = (pre_close - cash + payment) / (1.0 + bonus + ratio);
+            rows.push((ex_date, *pre_close, reference, pre_close / reference));
+        }
+
+        let cumulative: Vec<f64> = rows
+            .iter()
+            .scan(1.0, |acc, r| {
+                *acc *= r.3;
+                Some(*acc)
+            })
+            .collect();
+
+        let df = DataFrame::new(vec![
+            Series::new("除权除息日", rows.iter().map(|r| r.0).collect::<Vec<_>>()),
+            Series::new("前收盘", rows.iter().map(|r| r.1).collect::<Vec<_>>()),
+            Series::new("除权参考价", rows.iter().map(|r| r.2).collect::<Vec<_>>()),
+            Series::new("复权因子", rows.iter().map(|r| r.3).collect::<Vec<_>>()),
+            Series::new("累计复权因子", cumulative),
+        ])?;
+
+        Ok(df)
+    }
+}
+
+impl HttpSource for EastmoneyCorporateActionDataSource {
+    fn request(&self) -> Request {
+        EastmoneyDataCenter::request(
+            self.action.report_name(),
+            &self.filter(),
+            self.action.sort_column(),
+            "-1",
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyCorporateActionDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let mut ca = vec![("SECURITY_CODE", "代码"), ("SECURITY_NAME_ABBR", "名称")];
+
+        match self.action {
+            CorporateAction::Dividend => ca.extend([
+                ("REPORT_DATE", "报告期"),
+                ("PLAN_NOTICE_DATE", "预案公告日"),
+                ("NOTICE_DATE", "最新公告日期"),
+                ("EQUITY_RECORD_DATE", "股权登记日"),
+                ("EX_DIVIDEND_DATE", "除权除息日"),
+                ("ASSIGN_PROGRESS", "方案进度"),
+                ("IMPL_PLAN_PROFILE", "分配方案"),
+                ("PRETAX_BONUS_RMB", "现金分红"),
+                ("BONUS_IT_RATIO", "送转总比例"),
+                ("BONUS_RATIO", "送股比例"),
+                ("IT_RATIO", "转股比例"),
+                ("DIVIDENT_RATIO", "股息率"),
+                ("TOTAL_SHARES", "总股本"),
+            ]),
+            CorporateAction::RightsIssue => ca.extend([
+                ("NOTICE_DATE", "公告日期"),
+                ("EQUITY_RECORD_DATE", "股权登记日"),
+                ("EX_DIVIDEND_DATE", "除权日"),
+                ("LISTING_DATE", "上市日"),
+                ("PLACING_RATIO", "配股比例"),
+                ("ISSUE_PRICE", "配股价"),
+                ("ISSUE_NUM", "配股数量"),
+                ("TOTAL_SHARES_BEFORE", "配股前总股本"),
+                ("TOTAL_SHARES_AFTER", "配股后总股本"),
+            ]),
+            CorporateAction::ShareChange => ca.extend([
+                ("END_DATE", "变动日期"),
+                ("NOTICE_DATE", "公告日期"),
+                ("CHANGE_REASON", "变动原因"),
+                ("TOTAL_SHARES", "总股本"),
+                ("LISTED_A_SHARES", "流通A股"),
+                ("LIMITED_SHARES", "限售股"),
+            ]),
+        }
+        ca.push(("SECURITY_CODE", "symbol"));
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "现金分红" | "送转总比例" | "送股比例" | "转股比例" | "股息率" | "总股本"
+                | "配股比例" | "配股价" | "配股数量" | "配股前总股本" | "配股后总股本"
+                | "流通A股" | "限售股" => DataType::Float64,
+                _ => DataType::Utf8,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyCorporateActionDataSource {
+    ///
+    /// 个股全部历史公司行为，按日期倒序
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
@@ -3,10 +3,14 @@ use std::io::Cursor;
 use anyhow::Error;
 use async_trait::async_trait;
 use polars::{
+    export::chrono::NaiveDate,
     lazy::dsl::{col, Expr},
-    prelude::{DataFrame, DataType, IntoLazy, JsonFormat, JsonReader, Schema, SerReader},
+    prelude::{
+        DataFrame, DataType, IntoLazy, JsonFormat, JsonReader, NamedFrom, Schema, SerReader, Series,
+    },
 };
 use reqwest::{Method, Request, Url};
+use serde_json::Value;
 
 use crate::{
     utils::HttpClient, DataResult, DataResultFormat, HttpSource, RealTimeData, ResultCached,
@@ -21,21 +25,10 @@ pub struct EastmoneySpotEmDataSource {}
 
 impl HttpSource for EastmoneySpotEmDataSource {
     fn request(&self) -> Request {
-        let url = Url::parse_with_params("http://82.push2.eastmoney.com/api/qt/clist/get",
-                                         &[("pn", "1"),
-                                             ("pz", "10000"),
-                                             ("po", "1"),
-                                             ("np", "1"),
-                                             ("ut", "bd1d9ddb04089700cf9c27f6f7426281"),
-                                             ("fltt", "2"),
-                                             ("invt", "2"),
-                                             ("fid", "f3"),
-                                             ("fs", "m:0 t:6,m:0 t:80,m:1 t:2,m:1 t:23,m:0 t:81 s:2048"),
-                                             ("fields", "f1,f2,f3,f4,f5,f6,f7,f8,f9,f10,f12,f13,f14,f15,f16,f17,f18,f20,f21,f23,f24,f25,f22,f11,f62,f128,f136,f115,f152"),
-                                             ("_", "1623833739532"),
-                                         ]).unwrap();
-
-        Request::new(Method::GET, url.clone())
+        EastmoneyClist::request(
+            EastmoneyClist::A_SHARES,
+            "f1,f2,f3,f4,f5,f6,f7,f8,f9,f10,f12,f13,f14,f15,f16,f17,f18,f20,f21,f23,f24,f25,f22,f11,f62,f128,f136,f115,f152",
+        )
     }
 
     fn id(&self) -> String {
@@ -213,3 +206,336 @@ impl RealTimeData for EastmoneySpotEmDataSource {
         Some(schema)
     }
 }
+
+///
+/// 东方财富数据中心接口
+/// https://data.eastmoney.com/
+///
+pub(crate) struct EastmoneyDataCenter;
+
+impl EastmoneyDataCenter {
+    /// 每页条数
+    pub(crate) const PAGE_SIZE: usize = 5000;
+
+    ///
+    /// 构造数据中心请求，filter 如 (INDEX_CODE="000300")
+    ///
+    pub(crate) fn request(
+        report_name: &str,
+        filter: &str,
+        sort_columns: &str,
+        sort_types: &str,
+    ) -> Request {
+        EastmoneyDataCenter::request_page(report_name, filter, sort_columns, sort_types, 1)
+    }
+
+    ///
+    /// 构造数据中心分页请求，page 从 1 开始，每页 PAGE_SIZE 条
+    ///
+    pub(crate) fn request_page(
+        report_name: &str,
+        filter: &str,
+        sort_columns: &str,
+        sort_types: &str,
+        page: usize,
+    ) -> Request {
+        let url = Url::parse_with_params(
+            "https://datacenter-web.eastmoney.com/api/data/v1/get",
+            &[
+                ("reportName", report_name),
+                ("columns", "ALL"),
+                ("filter", filter),
+                ("pageNumber", &page.to_string()),
+                ("pageSize", &EastmoneyDataCenter::PAGE_SIZE.to_string()),
+                ("sortColumns", sort_columns),
+                ("sortTypes", sort_types),
+                ("source", "WEB"),
+                ("client", "WEB"),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+
+    ///
+    /// 解析响应中的 result.data，日期时间 2023-10-31 00:00:00 统一为 2023-10-31
+    ///
+    pub(crate) fn to_dataframe(body: &str) -> anyhow::Result<DataFrame> {
+        let json: Value = serde_json::from_str(body)?;
+
+        let rows: Vec<Value> = match json.pointer("/result/data") {
+            Some(Value::Array(rows)) if !rows.is_empty() => {
+                rows.iter().map(EastmoneyDataCenter::trim_date).collect()
+            }
+            _ => {
+                tracing::debug!("数据中心无数据: {:?}", json.get("message"));
+                return Ok(DataFrame::empty());
+            }
+        };
+
+        let file = Cursor::new(serde_json::to_string(&rows)?);
+        let df = JsonReader::new(file)
+            .with_json_format(JsonFormat::Json)
+            .finish()?;
+
+        Ok(df)
+    }
+
+    fn trim_date(row: &Value) -> Value {
+        let mut row = row.clone();
+        if let Some(obj) = row.as_object_mut() {
+            for v in obj.values_mut() {
+                if let Some(s) = v.as_str() {
+                    if s.len() == 19 && s.ends_with(" 00:00:00") {
+                        *v = Value::String(s[..10].to_string());
+                    }
+                }
+            }
+        }
+        row
+    }
+}
+
+///
+/// 东方财富行情列表接口(clist)，通过 fs 过滤市场/板块，通过 fields 指定返回字段
+///
+pub(crate) struct EastmoneyClist;
+
+impl EastmoneyClist {
+    /// 沪深京A股
+    pub(crate) const A_SHARES: &'static str = "m:0 t:6,m:0 t:80,m:1 t:2,m:1 t:23,m:0 t:81 s:2048";
+
+    /// 分页请求每页条数，接口单页最多返回100条
+    pub(crate) const PAGE_SIZE: usize = 100;
+
+    ///
+    /// 构造行情列表请求，fs 如 m:90 t:2 (行业板块)，按 f3(涨跌幅) 倒序
+    ///
+    pub(crate) fn request(fs: &str, fields: &str) -> Request {
+        EastmoneyClist::request_sorted(fs, fields, "f3")
+    }
+
+    ///
+    /// 构造行情列表请求，按 fid 字段倒序
+    ///
+    pub(crate) fn request_sorted(fs: &str, fields: &str, fid: &str) -> Request {
+        EastmoneyClist::request_with_page(fs, fields, fid, 1, 10000)
+    }
+
+    ///
+    /// 构造分页行情列表请求，page 从 1 开始，每页 PAGE_SIZE 条，按 fid 字段倒序
+    ///
+    /// 分页期间排序须稳定，fid 应为代码等不随行情变化的字段
+    ///
+    pub(crate) fn request_page(fs: &str, fields: &str, fid: &str, page: usize) -> Request {
+        EastmoneyClist::request_with_page(fs, fields, fid, page, EastmoneyClist::PAGE_SIZE)
+    }
+
+    fn request_with_page(
+        fs: &str,
+        fields: &str,
+        fid: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Request {
+        let url = Url::parse_with_params(
+            "http://82.push2.eastmoney.com/api/qt/clist/get",
+            &[
+                ("pn", page.to_string().as_str()),
+                ("pz", &page_size.to_string()),
+                ("po", "1"),
+                ("np", "1"),
+                ("ut", "bd1d9ddb04089700cf9c27f6f7426281"),
+                ("fltt", "2"),
+                ("invt", "2"),
+                ("fid", fid),
+                ("fs", fs),
+                ("fields", fields),
+                ("_", "1623833739532"),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+
+    ///
+    /// 解析响应中的 data.diff，无数据的 "-" 转为空值
+    ///
+    pub(crate) fn to_dataframe(body: &str) -> anyhow::Result<DataFrame> {
+        let json: Value = serde_json::from_str(body)?;
+
+        // np=1 时 diff 为数组，否则为以序号为key的对象
+        let rows: Vec<Value> = match json.pointer("/data/diff") {
+            Some(Value::Array(rows)) => rows.iter().map(EastmoneyClist::trim_empty).collect(),
+            Some(Value::Object(rows)) => rows.values().map(EastmoneyClist::trim_empty).collect(),
+            _ => vec![],
+        };
+        if rows.is_empty() {
+            tracing::debug!("行情列表无数据: {:?}", json.get("rc"));
+            return Ok(DataFrame::empty());
+        }
+
+        let file = Cursor::new(serde_json::to_string(&rows)?);
+        let df = JsonReader::new(file)
+            .with_json_format(JsonFormat::Json)
+            .finish()?;
+
+        Ok(df)
+    }
+
+    ///
+    /// 东方财富证券id, 沪市 1.600519, 深市/北交所 0.000001
+    ///
+    /// symbol 可带市场前缀，如 sh600519、sz000001、bj430047
+    ///
+    pub(crate) fn secid(symbol: &str) -> String {
+        let code = symbol.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+        let market = match &symbol[..symbol.len() - code.len()] {
+            "sh" | "SH" => "1",
+            "sz" | "SZ" | "bj" | "BJ" => "0",
+            _ if code.starts_with(['5', '6', '9']) => "1",
+            _ => "0",
+        };
+
+        format!("{}.{}", market, code)
+    }
+
+    fn trim_empty(row: &Value) -> Value {
+        let mut row = row.clone();
+        if let Some(obj) = row.as_object_mut() {
+            for v in obj.values_mut() {
+                if v.as_str() == Some("-") {
+                    *v = Value::Null;
+                }
+            }
+        }
+        row
+    }
+}
+
+///
+/// 东方财富K线接口，日K线字段: f51 日期, f52 开盘, f53 收盘, f54 最高, f55 最低, f56 成交量,
+/// f57 成交额, f58 振幅, f59 涨跌幅, f60 涨跌额, f61 换手率
+///
+#[derive(Clone, Debug)]
+pub(crate) struct EastmoneyKline;
+
+impl EastmoneyKline {
+    const FIELDS: [&'static str; 11] = [
+        "f51", "f52", "f53", "f54", "f55", "f56", "f57", "f58", "f59", "f60", "f61",
+    ];
+
+    ///
+    /// 构造日K线请求，fqt 复权类型: 0 不复权, 1 前复权, 2 后复权
+    ///
+    pub(crate) fn request(secid: &str, fqt: &str, start: NaiveDate, end: NaiveDate) -> Request {
+        let url = Url::parse_with_params(
+            "http://push2his.eastmoney.com/api/qt/stock/kline/get",
+            &[
+                ("secid", secid),
+                ("ut", "7eea3edcaed734bea9cbfc24409ed989"),
+                ("fields1", "f1,f2,f3,f4,f5,f6"),
+                ("fields2", &EastmoneyKline::FIELDS.join(",")),
+                ("klt", "101"),
+                ("fqt", fqt),
+                ("beg", &start.format("%Y%m%d").to_string()),
+                ("end", &end.format("%Y%m%d").to_string()),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+
+    ///
+    /// 解析响应中的 data.klines，列名为 code,name,f51..f61
+    ///
+    fn parse_klines(body: &str) -> anyhow::Result<DataFrame> {
+        let json: Value = serde_json::from_str(body)?;
+
+        let klines: Vec<Vec<&str>> = match json.pointer("/data/klines") {
+            Some(Value::Array(klines)) => klines
+                .iter()
+                .filter_map(|k| k.as_str())
+                .map(|k| k.split(',').collect())
+                .collect(),
+            _ => vec![],
+        };
+        if klines.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+
+        let code = json.pointer("/data/code").and_then(|v| v.as_str());
+        let name = json.pointer("/data/name").and_then(|v| v.as_str());
+
+        let mut columns = vec![
+            Series::new("code", vec![code; klines.len()]),
+            Series::new("name", vec![name; klines.len()]),
+            Series::new(
+                EastmoneyKline::FIELDS[0],
+                klines.iter().map(|k| k[0]).collect::<Vec<&str>>(),
+            ),
+        ];
+        for (i, field) in EastmoneyKline::FIELDS.iter().enumerate().skip(1) {
+            let values: Vec<Option<f64>> = klines
+                .iter()
+                .map(|k| k.get(i).and_then(|v| v.parse().ok()))
+                .collect();
+            columns.push(Series::new(field, values));
+        }
+
+        Ok(DataFrame::new(columns)?)
+    }
+}
+
+impl DataResultFormat for EastmoneyKline {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyKline::parse_klines(&body)?;
+            return Ok(DataResult::<DataFrame>::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("code", "代码"),
+            ("name", "名称"),
+            ("f51", "日期"),
+            ("f52", "开盘"),
+            ("f53", "收盘"),
+            ("f54", "最高"),
+            ("f55", "最低"),
+            ("f56", "成交量"),
+            ("f57", "成交额"),
+            ("f58", "振幅"),
+            ("f59", "涨跌幅"),
+            ("f60", "涨跌额"),
+            ("f61", "换手率"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("开盘".to_string(), DataType::Float64);
+        schema.with_column("收盘".to_string(), DataType::Float64);
+        schema.with_column("最高".to_string(), DataType::Float64);
+        schema.with_column("最低".to_string(), DataType::Float64);
+        schema.with_column("成交量".to_string(), DataType::Float64);
+        schema.with_column("成交额".to_string(), DataType::Float64);
+        schema.with_column("振幅".to_string(), DataType::Float64);
+        schema.with_column("涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("涨跌额".to_string(), DataType::Float64);
+        schema.with_column("换手率".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
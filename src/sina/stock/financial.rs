@@ -0,0 +1,267 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, Schema, UniqueKeepStrategy},
+};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyDataCenter, utils::HttpClient, DataResult, DataResultFormat,
+    HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 财务报表类型
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FinancialStatement {
+    /// 资产负债表
+    BalanceSheet,
+    /// 利润表
+    Income,
+    /// 现金流量表
+    CashFlow,
+    /// 主要指标(业绩报表): 每股收益、净资产收益率、销售毛利率等
+    Indicator,
+}
+
+impl FinancialStatement {
+    fn report_name(&self) -> &str {
+        match self {
+            FinancialStatement::BalanceSheet => "RPT_DMSK_FN_BALANCE",
+            FinancialStatement::Income => "RPT_DMSK_FN_INCOME",
+            FinancialStatement::CashFlow => "RPT_DMSK_FN_CASHFLOW",
+            FinancialStatement::Indicator => "RPT_LICO_FN_CPD",
+        }
+    }
+
+    ///
+    /// 报告期字段，业绩报表为 REPORTDATE，其余为 REPORT_DATE
+    ///
+    fn report_date_column(&self) -> &str {
+        match self {
+            FinancialStatement::Indicator => "REPORTDATE",
+            _ => "REPORT_DATE",
+        }
+    }
+}
+
+///
+/// 东方财富数据中心-年报季报-资产负债表、利润表、现金流量表、业绩报表
+/// https://data.eastmoney.com/bbsj/zcfz.html
+///
+/// 报告期为财报所属期末(如 2023-09-30)，公告日期为财报首次披露日，回测时应以公告日期作为数据可用日，
+/// 见 EastmoneyFinancialDataSource::point_in_time
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyFinancialDataSource {
+    pub statement: FinancialStatement,
+    /// 股票代码，如 600519，None 时返回全部A股
+    pub symbol: Option<String>,
+    /// 报告期，如 2023-09-30，None 时返回全部报告期
+    pub report_date: Option<NaiveDate>,
+}
+
+impl EastmoneyFinancialDataSource {
+    fn filter(&self, symbol: Option<&str>, date_filter: &str) -> String {
+        let security = match symbol {
+            Some(symbol) => format!(
+                "(SECURITY_CODE=\"{}\")",
+                symbol.trim_start_matches(|c: char| c.is_ascii_alphabetic())
+            ),
+            None => "(SECURITY_TYPE_CODE in (\"058001001\",\"058001008\"))(TRADE_MARKET_CODE!=\"069001017\")".to_string(),
+        };
+
+        format!("{}{}", security, date_filter)
+    }
+
+    fn report_date_filter(&self) -> String {
+        match self.report_date {
+            Some(date) => format!(
+                "({}='{}')",
+                self.statement.report_date_column(),
+                date.format("%Y-%m-%d")
+            ),
+            None => "".to_string(),
+        }
+    }
+
+    fn request_page(&self, filter: &str, page: usize) -> Request {
+        EastmoneyDataCenter::request_page(
+            self.statement.report_name(),
+            filter,
+            &format!("{},SECURITY_CODE", self.statement.report_date_column()),
+            "-1,1",
+            page,
+        )
+    }
+
+    ///
+    /// 按 filter 获取全部分页
+    ///
+    async fn fetch(&self, filter: &str) -> anyhow::Result<DataFrame> {
+        HttpClient::exec_by_pages(self, EastmoneyDataCenter::PAGE_SIZE, |page| {
+            self.request_page(filter, page)
+        })
+        .await
+    }
+
+    ///
+    /// 时点数据: 每只股票在 date 当日已公告的最新一期报表，避免回测使用未来数据
+    ///
+    pub fn point_in_time(df: &DataFrame, date: NaiveDate) -> anyhow::Result<DataFrame> {
+        let df = df
+            .clone()
+            .lazy()
+            .filter(col("公告日期").lt_eq(lit(date.format("%Y-%m-%d").to_string())))
+            .sort_by_exprs([col("代码"), col("报告期")], [false, true], true)
+            .unique_stable(Some(vec!["代码".to_string()]), UniqueKeepStrategy::First)
+            .collect()?;
+
+        Ok(df)
+    }
+}
+
+impl HttpSource for EastmoneyFinancialDataSource {
+    fn request(&self) -> Request {
+        self.request_page(
+            &self.filter(self.symbol.as_deref(), &self.report_date_filter()),
+            1,
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyFinancialDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let mut ca = vec![
+            ("SECURITY_CODE", "代码"),
+            ("SECURITY_NAME_ABBR", "名称"),
+            (self.statement.report_date_column(), "报告期"),
+            ("NOTICE_DATE", "公告日期"),
+        ];
+
+        match self.statement {
+            FinancialStatement::BalanceSheet => ca.extend([
+                ("MONETARYFUNDS", "货币资金"),
+                ("ACCOUNTS_RECE", "应收账款"),
+                ("INVENTORY", "存货"),
+                ("TOTAL_ASSETS", "总资产"),
+                ("TOTAL_ASSETS_RATIO", "总资产同比"),
+                ("ACCOUNTS_PAYABLE", "应付账款"),
+                ("ADVANCE_RECEIVABLES", "预收账款"),
+                ("TOTAL_LIABILITIES", "总负债"),
+                ("TOTAL_LIAB_RATIO", "总负债同比"),
+                ("DEBT_ASSET_RATIO", "资产负债率"),
+                ("TOTAL_EQUITY", "股东权益合计"),
+            ]),
+            FinancialStatement::Income => ca.extend([
+                ("TOTAL_OPERATE_INCOME", "营业总收入"),
+                ("TOI_RATIO", "营业总收入同比"),
+                ("OPERATE_EXPENSE", "营业支出"),
+                ("SALE_EXPENSE", "销售费用"),
+                ("MANAGE_EXPENSE", "管理费用"),
+                ("FINANCE_EXPENSE", "财务费用"),
+                ("TOTAL_OPERATE_COST", "营业总支出"),
+                ("OPERATE_PROFIT", "营业利润"),
+                ("TOTAL_PROFIT", "利润总额"),
+                ("PARENT_NETPROFIT", "净利润"),
+                ("PARENT_NETPROFIT_RATIO", "净利润同比"),
+            ]),
+            FinancialStatement::CashFlow => ca.extend([
+                ("NETCASH_OPERATE", "经营性现金流净额"),
+                ("NETCASH_OPERATE_RATIO", "经营性现金流净额占比"),
+                ("SALES_SERVICES", "销售商品收到的现金"),
+                ("NETCASH_INVEST", "投资性现金流净额"),
+                ("NETCASH_FINANCE", "融资性现金流净额"),
+                ("CCE_ADD", "净现金流"),
+            ]),
+            FinancialStatement::Indicator => ca.extend([
+                ("UPDATE_DATE", "更新日期"),
+                ("BASIC_EPS", "每股收益"),
+                ("DEDUCT_BASIC_EPS", "扣非每股收益"),
+                ("TOTAL_OPERATE_INCOME", "营业总收入"),
+                ("YSTZ", "营业总收入同比"),
+                ("PARENT_NETPROFIT", "净利润"),
+                ("SJLTZ", "净利润同比"),
+                ("BPS", "每股净资产"),
+                ("WEIGHTAVG_ROE", "净资产收益率"),
+                ("MGJYXJJE", "每股经营现金流"),
+                ("XSMLL", "销售毛利率"),
+            ]),
+        }
+        ca.push(("SECURITY_CODE", "symbol"));
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "代码" | "名称" | "报告期" | "公告日期" | "更新日期" | "symbol" => {
+                    DataType::Utf8
+                }
+                _ => DataType::Float64,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyFinancialDataSource {
+    ///
+    /// 按股票代码和报告期查询财务报表，按报告期倒序
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let df = self
+            .fetch(&self.filter(self.symbol.as_deref(), &self.report_date_filter()))
+            .await?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyFinancialDataSource {
+    ///
+    /// 报告期在 [start, end] 内的财务报表，market 可传空，symbol 为股票代码，传空时返回全部A股
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let column = self.statement.report_date_column();
+        let date_filter = format!(
+            "({}>='{}')({}<='{}')",
+            column,
+            start.format("%Y-%m-%d"),
+            column,
+            end.format("%Y-%m-%d")
+        );
+        let symbol = Some(symbol).filter(|s| !s.is_empty());
+        let df = self.fetch(&self.filter(symbol, &date_filter)).await?;
+
+        Ok(DataResult::new(symbol.unwrap_or("").to_string(), df))
+    }
+}
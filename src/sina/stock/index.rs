@@ -0,0 +1,169 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, Schema, TakeRandomUtf8},
+};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyDataCenter, utils::HttpClient, DataResult, DataResultFormat,
+    HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 东方财富数据中心-指数成分股及权重, 如沪深300、中证500、中证1000、上证50
+///
+/// symbol 与 SinaIndexSpotDataSource 结果中的 symbol 列一致(如 sh000300)，
+/// 两者可按 symbol 列关联指数行情与成分股
+///
+#[derive(Clone, Debug)]
+pub struct IndexConstituentDataSource {
+    /// 指数symbol, 如 sh000300、sh000905、sh000852、sh000016
+    pub symbol: String,
+    /// 查询日期, 返回该日期生效的成分股及权重, None 时返回最新一期
+    pub date: Option<NaiveDate>,
+}
+
+impl IndexConstituentDataSource {
+    ///
+    /// 去掉市场前缀的指数代码, sh000300 -> 000300
+    ///
+    fn index_code(&self) -> &str {
+        self.symbol
+            .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+    }
+
+    fn request_page(&self, date_filter: &str, page: usize) -> Request {
+        let filter = format!("(INDEX_CODE=\"{}\"){}", self.index_code(), date_filter);
+        EastmoneyDataCenter::request_page(
+            "RPT_INDEX_TS_COMPONENT",
+            &filter,
+            "TRADE_DATE,SECURITY_CODE",
+            "-1,1",
+            page,
+        )
+    }
+}
+
+impl HttpSource for IndexConstituentDataSource {
+    fn request(&self) -> Request {
+        let date_filter = match self.date {
+            Some(date) => format!("(TRADE_DATE<='{}')", date.format("%Y-%m-%d")),
+            None => "".to_string(),
+        };
+
+        self.request_page(&date_filter, 1)
+    }
+}
+
+impl DataResultFormat for IndexConstituentDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            if df.height() == 0 {
+                return Ok(DataResult::new("".to_string(), df));
+            }
+
+            // 增加指数symbol列，用于关联指数行情
+            let df = df
+                .lazy()
+                .with_column(lit(self.symbol.as_str()).alias("symbol"))
+                .collect()?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("symbol", "symbol"),
+            ("INDEX_CODE", "指数代码"),
+            ("SECURITY_CODE", "代码"),
+            ("SECURITY_NAME_ABBR", "名称"),
+            ("WEIGHT", "权重"),
+            ("TRADE_DATE", "生效日期"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+        schema.with_column("指数代码".to_string(), DataType::Utf8);
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("权重".to_string(), DataType::Float64);
+        schema.with_column("生效日期".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for IndexConstituentDataSource {
+    ///
+    /// 指定日期(或最新)生效的指数成分股及权重
+    /// :return: symbol,指数代码,代码,名称,权重,生效日期
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let mut result = HttpClient::exec_by_cache(self.request(), self.clone()).await?;
+
+        // 结果按生效日期倒序，只保留最近一期
+        if let Some(df) = &result.data {
+            let latest = match df.column("生效日期") {
+                Ok(dates) if df.height() > 0 => dates.utf8()?.get(0).map(|d| d.to_string()),
+                _ => None,
+            };
+            if let Some(latest) = latest {
+                let df = df
+                    .clone()
+                    .lazy()
+                    .filter(col("生效日期").eq(lit(latest)))
+                    .collect()?;
+                result.data = Some(df);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for IndexConstituentDataSource {
+    ///
+    /// [start, end] 期间各期指数成分股及权重，按页获取全部结果
+    ///
+    async fn history_daily(
+        self,
+        market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let source = IndexConstituentDataSource {
+            symbol: format!("{}{}", market, symbol),
+            date: Some(end),
+        };
+        let date_filter = format!(
+            "(TRADE_DATE>='{}')(TRADE_DATE<='{}')",
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        );
+
+        let df = HttpClient::exec_by_pages(&source, EastmoneyDataCenter::PAGE_SIZE, |page| {
+            source.request_page(&date_filter, page)
+        })
+        .await?;
+
+        Ok(DataResult::new(source.symbol.clone(), df))
+    }
+}
@@ -0,0 +1,158 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Local, NaiveDate},
+    lazy::dsl::col,
+    prelude::{DataFrame, DataType, IntoLazy, Schema},
+};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyDataCenter, utils::HttpClient, DataResult, DataResultFormat,
+    HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 东方财富数据中心-新股申购与中签查询
+/// https://data.eastmoney.com/xg/xg/default.html
+///
+/// 包括申购日期、发行价、发行市盈率、中签率、上市日期及上市首日表现，
+/// 中签率、首日涨幅单位为 %，发行数量单位为万股，尚未上市的新股首日数据为空
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyIpoDataSource {}
+
+impl EastmoneyIpoDataSource {
+    fn request_page(&self, filter: &str, page: usize) -> Request {
+        EastmoneyDataCenter::request_page(
+            "RPTA_APP_IPOAPPLY",
+            filter,
+            "APPLY_DATE,SECURITY_CODE",
+            "-1,-1",
+            page,
+        )
+    }
+
+    ///
+    /// 按 filter 获取全部分页
+    ///
+    async fn fetch(&self, filter: &str) -> anyhow::Result<DataFrame> {
+        HttpClient::exec_by_pages(self, EastmoneyDataCenter::PAGE_SIZE, |page| {
+            self.request_page(filter, page)
+        })
+        .await
+    }
+
+    ///
+    /// 今日及之后开放申购的新股，按申购日期升序
+    ///
+    pub async fn upcoming(&self) -> Result<DataResult<DataFrame>, Error> {
+        let today = Local::now().date_naive();
+        let df = self
+            .fetch(&format!("(APPLY_DATE>='{}')", today.format("%Y-%m-%d")))
+            .await?
+            .lazy()
+            .sort_by_exprs([col("申购日期"), col("代码")], [false, false], false)
+            .collect()?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+}
+
+impl HttpSource for EastmoneyIpoDataSource {
+    fn request(&self) -> Request {
+        self.request_page("", 1)
+    }
+}
+
+impl DataResultFormat for EastmoneyIpoDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("SECURITY_CODE", "代码"),
+            ("SECURITY_NAME", "名称"),
+            ("APPLY_CODE", "申购代码"),
+            ("TRADE_MARKET", "交易所"),
+            ("APPLY_DATE", "申购日期"),
+            ("BALLOT_NUM_DATE", "中签号公布日"),
+            ("BALLOT_PAY_DATE", "中签缴款日"),
+            ("LISTING_DATE", "上市日期"),
+            ("ISSUE_PRICE", "发行价"),
+            ("AFTER_ISSUE_PE", "发行市盈率"),
+            ("INDUSTRY_PE_NEW", "行业市盈率"),
+            ("ISSUE_NUM", "发行总数"),
+            ("ONLINE_ISSUE_NUM", "网上发行数"),
+            ("ONLINE_APPLY_UPPER", "申购上限"),
+            ("TOP_APPLY_MARKETCAP", "顶格申购需配市值"),
+            ("ONLINE_ISSUE_LWR", "中签率"),
+            ("INITIAL_MULTIPLE", "询价累计报价倍数"),
+            ("CLOSE_PRICE", "首日收盘价"),
+            ("LD_CLOSE_CHANGE", "首日涨幅"),
+            ("PER_SHARES_INCOME", "每中一签获利"),
+            ("SECURITY_CODE", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "代码" | "名称" | "申购代码" | "交易所" | "申购日期" | "中签号公布日"
+                | "中签缴款日" | "上市日期" | "symbol" => DataType::Utf8,
+                _ => DataType::Float64,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyIpoDataSource {
+    ///
+    /// 全部新股申购记录，按申购日期倒序
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let df = self.fetch("").await?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyIpoDataSource {
+    ///
+    /// 申购日期在 [start, end] 内的新股，market、symbol 不区分传空即可，按申购日期倒序
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        _symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let filter = format!(
+            "(APPLY_DATE>='{}')(APPLY_DATE<='{}')",
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        );
+        let df = self.fetch(&filter).await?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+}
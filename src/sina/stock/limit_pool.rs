@@ -0,0 +1,276 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Datelike, Duration, Local, NaiveDate, Weekday},
+    lazy::dsl::col,
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::{Method, Request, Url};
+use serde_json::Value;
+
+use crate::{
+    utils::HttpClient, DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 涨跌停股池类型
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LimitPool {
+    /// 涨停股池
+    LimitUp,
+    /// 跌停股池
+    LimitDown,
+    /// 炸板股池: 盘中曾涨停但收盘未封住
+    BrokenLimit,
+}
+
+impl LimitPool {
+    ///
+    /// 接口名及默认排序
+    ///
+    fn topic(&self) -> (&str, &str) {
+        match self {
+            LimitPool::LimitUp => ("getTopicZTPool", "fbt:asc"),
+            LimitPool::LimitDown => ("getTopicDTPool", "fund:asc"),
+            LimitPool::BrokenLimit => ("getTopicZBPool", "fbt:asc"),
+        }
+    }
+
+    ///
+    /// 连板数、炸板次数字段，跌停股池为连续跌停天数、开板次数
+    ///
+    fn count_keys(&self) -> (&str, &str) {
+        match self {
+            LimitPool::LimitDown => ("days", "oc"),
+            _ => ("lbc", "zbc"),
+        }
+    }
+}
+
+///
+/// 东方财富网-行情中心-涨停板行情
+/// https://quote.eastmoney.com/ztb/detail#type=ztgc
+///
+/// 价格单位为元，金额、市值单位为元，时间为北京时间 HH:MM:SS，
+/// 涨停统计如 5天3板 表示最近5个交易日中3次涨停，接口仅保留最近约30个交易日
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyLimitPoolDataSource {
+    pub pool: LimitPool,
+}
+
+impl EastmoneyLimitPoolDataSource {
+    /// 接口保留的自然日数，约30个交易日，更早的日期无数据
+    pub const RETENTION_DAYS: i64 = 45;
+
+    fn request_by_date(&self, date: NaiveDate) -> Request {
+        let (topic, sort) = self.pool.topic();
+        let url = Url::parse_with_params(
+            &format!("https://push2ex.eastmoney.com/{}", topic),
+            &[
+                ("ut", "7eea3edcaed734bea9cbfc24409ed989"),
+                ("dpt", "wz.ztzt"),
+                ("Pageindex", "0"),
+                ("pagesize", "10000"),
+                ("sort", sort),
+                ("date", &date.format("%Y%m%d").to_string()),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+}
+
+impl HttpSource for EastmoneyLimitPoolDataSource {
+    fn request(&self) -> Request {
+        self.request_by_date(Local::now().date_naive())
+    }
+}
+
+impl DataResultFormat for EastmoneyLimitPoolDataSource {
+    ///
+    /// data.pool 中价格为 元*1000，时间为 93000 形式的整数
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(&body)?;
+
+            let rows = match json.pointer("/data/pool") {
+                Some(Value::Array(rows)) if !rows.is_empty() => rows,
+                _ => return Ok(DataResult::new("".to_string(), DataFrame::empty())),
+            };
+            // 20231031 -> 2023-10-31
+            let date = json
+                .pointer("/data/qdate")
+                .map(|d| d.to_string())
+                .filter(|d| d.len() == 8)
+                .map(|d| format!("{}-{}-{}", &d[..4], &d[4..6], &d[6..]));
+
+            let text = |key: &str| -> Vec<Option<&str>> {
+                rows.iter()
+                    .map(|row| row.get(key).and_then(|v| v.as_str()))
+                    .collect()
+            };
+            let number = |key: &str| -> Vec<Option<f64>> {
+                rows.iter()
+                    .map(|row| row.get(key).and_then(|v| v.as_f64()))
+                    .collect()
+            };
+            let price = |key: &str| -> Vec<Option<f64>> {
+                number(key)
+                    .into_iter()
+                    .map(|p| p.map(|p| p / 1000.0))
+                    .collect()
+            };
+            let time = |key: &str| -> Vec<Option<String>> {
+                rows.iter()
+                    .map(|row| {
+                        let t = row.get(key)?.as_i64()?;
+                        Some(format!(
+                            "{:02}:{:02}:{:02}",
+                            t / 10000,
+                            t / 100 % 100,
+                            t % 100
+                        ))
+                    })
+                    .collect()
+            };
+            let stats: Vec<Option<String>> = rows
+                .iter()
+                .map(|row| {
+                    let stat = row.get("zttj")?;
+                    let days = stat.get("days")?.as_i64()?;
+                    let count = stat.get("ct")?.as_i64()?;
+                    Some(format!("{}天{}板", days, count))
+                })
+                .collect();
+
+            let (boards, breaks) = self.pool.count_keys();
+            let df = DataFrame::new(vec![
+                Series::new("date", vec![date; rows.len()]),
+                Series::new("c", text("c")),
+                Series::new("n", text("n")),
+                Series::new("p", price("p")),
+                Series::new("ztp", price("ztp")),
+                Series::new("zdp", number("zdp")),
+                Series::new("amount", number("amount")),
+                Series::new("ltsz", number("ltsz")),
+                Series::new("tshare", number("tshare")),
+                Series::new("hs", number("hs")),
+                Series::new("fund", number("fund")),
+                Series::new("fba", number("fba")),
+                Series::new("fbt", time("fbt")),
+                Series::new("lbt", time("lbt")),
+                Series::new("boards", number(boards)),
+                Series::new("breaks", number(breaks)),
+                Series::new("zttj", stats),
+                Series::new("hybk", text("hybk")),
+            ])?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("date", "日期"),
+            ("c", "代码"),
+            ("n", "名称"),
+            ("p", "最新价"),
+            ("ztp", "涨停价"),
+            ("zdp", "涨跌幅"),
+            ("amount", "成交额"),
+            ("ltsz", "流通市值"),
+            ("tshare", "总市值"),
+            ("hs", "换手率"),
+            ("fund", "封板资金"),
+            ("fba", "板上成交额"),
+            ("fbt", "首次封板时间"),
+            ("lbt", "最后封板时间"),
+            ("boards", "连板数"),
+            ("breaks", "炸板次数"),
+            ("zttj", "涨停统计"),
+            ("hybk", "所属行业"),
+            ("c", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "日期" | "代码" | "名称" | "首次封板时间" | "最后封板时间" | "涨停统计"
+                | "所属行业" | "symbol" => DataType::Utf8,
+                "连板数" | "炸板次数" => DataType::Int64,
+                _ => DataType::Float64,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyLimitPoolDataSource {
+    ///
+    /// 当日涨跌停股池，非交易日或开盘前为空，盘中持续变化，不缓存
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_and_format(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyLimitPoolDataSource {
+    ///
+    /// [start, end] 期间每日的涨跌停股池，market、symbol 不区分传空即可，按日期倒序
+    ///
+    /// 按日查询，今日之前的股池已收盘定稿，永久缓存(节假日无数据同样缓存)，今日股池盘中持续变化，不缓存
+    ///
+    /// 接口仅保留最近 RETENTION_DAYS 个自然日，start 早于该范围时从范围起始日开始查询
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        _symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let today = Local::now().date_naive();
+        let mut data_frame = self.col_schema().as_ref().map(DataFrame::from).unwrap();
+
+        let mut date = start.max(today - Duration::days(Self::RETENTION_DAYS));
+        while date <= end {
+            if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+                let request = self.request_by_date(date);
+                let result = if date < today {
+                    HttpClient::exec_by_settled_cache(request, self.clone()).await?
+                } else {
+                    HttpClient::exec_and_format(request, self.clone()).await?
+                };
+                if let Some(df) = result.data {
+                    data_frame.vstack_mut(&df)?;
+                }
+            }
+            date += Duration::days(1);
+        }
+
+        let df = data_frame
+            .lazy()
+            .sort_by_exprs([col("日期")], [true], false)
+            .collect()?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+}
@@ -0,0 +1,399 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Datelike, Duration, Local, NaiveDate, Weekday},
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::{
+    header::{HeaderValue, REFERER},
+    Method, Request, Url,
+};
+use serde_json::Value;
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyDataCenter,
+    utils::{DateUtils, HttpClient, JsonUtils},
+    DataResult, DataResultFormat, HistoryData, HttpSource,
+};
+
+///
+/// 两融数据于下一交易日披露，今日之前的数据可能尚未披露，早于该天数的数据才视为已定稿，留出长假的余量
+///
+const SETTLED_DAYS: i64 = 15;
+
+///
+/// 融资融券交易所
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MarginExchange {
+    /// 上海证券交易所
+    Sse,
+    /// 深圳证券交易所
+    Szse,
+}
+
+///
+/// 沪深交易所-融资融券交易汇总(日)
+/// 上交所: http://www.sse.com.cn/market/othersdata/margin/sum/
+/// 深交所: http://www.szse.cn/disclosure/margin/margin/index.html
+///
+/// 金额单位统一为元，数量单位统一为股(份)
+///
+#[derive(Clone, Debug)]
+pub struct ExchangeMarginDataSource {
+    pub exchange: MarginExchange,
+}
+
+impl ExchangeMarginDataSource {
+    ///
+    /// 上交所按日期区间查询，深交所按单日查询(end 即查询日)
+    ///
+    fn request_by_date(&self, start: NaiveDate, end: NaiveDate) -> Request {
+        match self.exchange {
+            MarginExchange::Sse => {
+                let url = Url::parse_with_params(
+                    "http://query.sse.com.cn/marketdata/tradedata/queryMargin.do",
+                    &[
+                        ("isPagination", "true"),
+                        ("beginDate", &start.format("%Y%m%d").to_string()),
+                        ("endDate", &end.format("%Y%m%d").to_string()),
+                        ("tabType", ""),
+                        ("stockCode", ""),
+                        ("pageHelp.pageSize", "5000"),
+                        ("pageHelp.pageNo", "1"),
+                        ("pageHelp.beginPage", "1"),
+                        ("pageHelp.cacheSize", "1"),
+                        ("pageHelp.endPage", "1"),
+                    ],
+                )
+                .unwrap();
+
+                // 接口校验 referer
+                let mut request = Request::new(Method::GET, url);
+                request
+                    .headers_mut()
+                    .insert(REFERER, HeaderValue::from_static("http://www.sse.com.cn/"));
+
+                request
+            }
+            MarginExchange::Szse => {
+                let url = Url::parse_with_params(
+                    "http://www.szse.cn/api/report/ShowReport/data",
+                    &[
+                        ("SHOWTYPE", "JSON"),
+                        ("CATALOGID", "1837_xxpl"),
+                        ("txtDate", &end.format("%Y-%m-%d").to_string()),
+                        ("tab1PAGENO", "1"),
+                    ],
+                )
+                .unwrap();
+
+                Request::new(Method::GET, url)
+            }
+        }
+    }
+
+    ///
+    /// 上交所 result 数组，金额单位为元
+    ///
+    fn parse_sse(json: &Value) -> Vec<[Option<f64>; 6]> {
+        let rows = match json.pointer("/result") {
+            Some(Value::Array(rows)) => rows,
+            _ => return vec![],
+        };
+
+        rows.iter()
+            .map(|row| {
+                ["rzye", "rzmre", "rqyl", "rqylje", "rqmcl", "rzrqjyzl"]
+                    .map(|k| JsonUtils::number(row.get(k)))
+            })
+            .collect()
+    }
+
+    ///
+    /// 深交所首个 tab 为汇总数据，金额单位为亿元，数量单位为亿股(份)
+    ///
+    fn parse_szse(json: &Value) -> Vec<[Option<f64>; 6]> {
+        let rows = match json.pointer("/0/data") {
+            Some(Value::Array(rows)) => rows,
+            _ => return vec![],
+        };
+
+        rows.iter()
+            .map(|row| {
+                ["jrrzye", "jrrzmr", "jrrjyl", "jrrjye", "jrrjmc", "jrrzrjye"]
+                    .map(|k| JsonUtils::number(row.get(k)).map(|v| v * 1e8))
+            })
+            .collect()
+    }
+}
+
+impl HttpSource for ExchangeMarginDataSource {
+    fn request(&self) -> Request {
+        let today = Local::now().date_naive();
+        self.request_by_date(today, today)
+    }
+}
+
+impl DataResultFormat for ExchangeMarginDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(&body)?;
+
+            let (dates, rows): (Vec<Option<String>>, Vec<_>) = match self.exchange {
+                MarginExchange::Sse => {
+                    let dates = match json.pointer("/result") {
+                        Some(Value::Array(rows)) => rows
+                            .iter()
+                            .map(|row| {
+                                row.get("opDate")
+                                    .and_then(|d| d.as_str())
+                                    .and_then(DateUtils::normalize_ymd)
+                            })
+                            .collect(),
+                        _ => vec![],
+                    };
+                    (dates, ExchangeMarginDataSource::parse_sse(&json))
+                }
+                // 深交所返回数据不含日期，由 history_daily 按查询日补充
+                MarginExchange::Szse => {
+                    let rows = ExchangeMarginDataSource::parse_szse(&json);
+                    (vec![None; rows.len()], rows)
+                }
+            };
+            if rows.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let mut columns = vec![Series::new("date", dates)];
+            for (i, name) in ["rzye", "rzmre", "rqyl", "rqye", "rqmcl", "rzrqye"]
+                .iter()
+                .enumerate()
+            {
+                columns.push(Series::new(
+                    name,
+                    rows.iter().map(|r| r[i]).collect::<Vec<_>>(),
+                ));
+            }
+
+            return Ok(DataResult::new("".to_string(), DataFrame::new(columns)?));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("date", "日期"),
+            ("rzye", "融资余额"),
+            ("rzmre", "融资买入额"),
+            ("rqyl", "融券余量"),
+            ("rqye", "融券余额"),
+            ("rqmcl", "融券卖出量"),
+            ("rzrqye", "融资融券余额"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("融资余额".to_string(), DataType::Float64);
+        schema.with_column("融资买入额".to_string(), DataType::Float64);
+        schema.with_column("融券余量".to_string(), DataType::Float64);
+        schema.with_column("融券余额".to_string(), DataType::Float64);
+        schema.with_column("融券卖出量".to_string(), DataType::Float64);
+        schema.with_column("融资融券余额".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for ExchangeMarginDataSource {
+    ///
+    /// [start, end] 期间交易所两融汇总，market、symbol 不区分传空即可，按日期倒序
+    ///
+    /// 上交所按自然月查询，深交所逐日查询并跳过周末，已定稿部分永久缓存，深交所已定稿的节假日无数据同样缓存
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        _symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let df = match self.exchange {
+            MarginExchange::Sse => {
+                fetch_range(&self, start, end, |s, e| self.request_by_date(s, e)).await?
+            }
+            MarginExchange::Szse => {
+                let mut data_frame = self.col_schema().as_ref().map(DataFrame::from).unwrap();
+                let mut date = start;
+                while date <= end {
+                    if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+                        let request = self.request_by_date(date, date);
+                        let result = if date <= DateUtils::settled_until(SETTLED_DAYS) {
+                            HttpClient::exec_by_settled_cache(request, self.clone()).await?
+                        } else {
+                            HttpClient::exec_by_cache(request, self.clone()).await?
+                        };
+                        if let Some(mut df) = result.data {
+                            let dates = vec![date.format("%Y-%m-%d").to_string(); df.height()];
+                            df.with_column(Series::new("日期", dates))?;
+                            data_frame.vstack_mut(&df)?;
+                        }
+                    }
+                    date += Duration::days(1);
+                }
+                data_frame
+            }
+        };
+
+        let df = df
+            .lazy()
+            .sort_by_exprs([col("日期")], [true], false)
+            .collect()?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+}
+
+///
+/// 东方财富数据中心-融资融券-个股融资融券明细(日)
+/// https://data.eastmoney.com/rzrq/detail/600519.html
+///
+/// 金额单位为元，数量单位为股
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyMarginDetailDataSource {}
+
+impl EastmoneyMarginDetailDataSource {
+    fn request_by_date(&self, symbol: &str, start: NaiveDate, end: NaiveDate) -> Request {
+        let filter = format!(
+            "(SCODE=\"{}\")(DATE>='{}')(DATE<='{}')",
+            symbol.trim_start_matches(|c: char| c.is_ascii_alphabetic()),
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        );
+        EastmoneyDataCenter::request("RPTA_WEB_RZRQ_GGMX", &filter, "DATE", "-1")
+    }
+}
+
+impl HttpSource for EastmoneyMarginDetailDataSource {
+    fn request(&self) -> Request {
+        EastmoneyDataCenter::request("RPTA_WEB_RZRQ_GGMX", "", "DATE", "-1")
+    }
+}
+
+impl DataResultFormat for EastmoneyMarginDetailDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("SCODE", "代码"),
+            ("SECNAME", "名称"),
+            ("DATE", "日期"),
+            ("SPJ", "收盘价"),
+            ("ZDF", "涨跌幅"),
+            ("RZYE", "融资余额"),
+            ("RZMRE", "融资买入额"),
+            ("RZCHE", "融资偿还额"),
+            ("RZJME", "融资净买入"),
+            ("RQYE", "融券余额"),
+            ("RQYL", "融券余量"),
+            ("RQMCL", "融券卖出量"),
+            ("RQCHL", "融券偿还量"),
+            ("RQJMG", "融券净卖出"),
+            ("RZRQYE", "融资融券余额"),
+            ("SCODE", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "代码" | "名称" | "日期" | "symbol" => DataType::Utf8,
+                _ => DataType::Float64,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyMarginDetailDataSource {
+    ///
+    /// [start, end] 期间个股两融明细，market 不区分传空即可，symbol 为股票代码，按日期倒序
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let df = fetch_range(&self, start, end, |s, e| self.request_by_date(symbol, s, e)).await?;
+
+        let df = df
+            .lazy()
+            .sort_by_exprs([col("日期")], [true], false)
+            .collect()?;
+
+        Ok(DataResult::new(symbol.to_string(), df))
+    }
+}
+
+///
+/// 按 DateUtils::cache_periods 拆分查询并合并，结果按 [start, end] 过滤
+///
+async fn fetch_range<F>(
+    format: &F,
+    start: NaiveDate,
+    end: NaiveDate,
+    request_by_date: impl Fn(NaiveDate, NaiveDate) -> Request,
+) -> anyhow::Result<DataFrame>
+where
+    F: DataResultFormat + Clone,
+{
+    let mut data_frame = format.col_schema().as_ref().map(DataFrame::from).unwrap();
+
+    for (period_start, period_end, permanent) in
+        DateUtils::cache_periods(start, end, DateUtils::settled_until(SETTLED_DAYS))
+    {
+        let request = request_by_date(period_start, period_end);
+        let result = if permanent {
+            HttpClient::exec_by_permanent_cache(request, format.clone()).await?
+        } else {
+            HttpClient::exec_by_cache(request, format.clone()).await?
+        };
+        if let Some(df) = result.data {
+            data_frame.vstack_mut(&df)?;
+        }
+    }
+
+    let df = data_frame
+        .lazy()
+        .filter(
+            col("日期")
+                .gt_eq(lit(start.format("%Y-%m-%d").to_string()))
+                .and(col("日期").lt_eq(lit(end.format("%Y-%m-%d").to_string()))),
+        )
+        .collect()?;
+
+    Ok(df)
+}
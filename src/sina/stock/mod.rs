@@ -1,5 +1,47 @@
+/// 龙虎榜及大宗交易
+pub mod billboard;
+
+/// 行业、概念板块
+pub mod board;
+
+/// 沪深港通资金流向及持股
+pub mod connect;
+
+/// 分红送配、配股及股本变动
+pub mod corporate_action;
+
 /// 东方财富数据源
 pub mod eastmoney;
 
+/// 财务报表及主要指标
+pub mod financial;
+
+/// 指数成分股及权重
+pub mod index;
+
+/// 新股申购及上市日历
+pub mod ipo;
+
+/// 涨停、跌停及炸板股池
+pub mod limit_pool;
+
+/// 融资融券
+pub mod margin;
+
+/// 个股资金流向
+pub mod money_flow;
+
+/// 个股公告及资讯
+pub mod news;
+
+/// 港股、美股及AH股比价
+pub mod overseas;
+
+/// 证券主表: 上市退市信息及更名历史
+pub mod security;
+
+/// 十大股东、股东户数及基金持股
+pub mod shareholder;
+
 /// 新浪财经数据源
 pub mod sina;
@@ -0,0 +1,334 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::{Method, Request, Url};
+use serde_json::Value;
+
+use crate::{
+    sina::stock::eastmoney::EastmoneyClist, utils::HttpClient, DataResult, DataResultFormat,
+    HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 资金流向列名定义，(中文列名, 英文列名)
+///
+/// 主力 = 超大单 + 大单，净额单位为元，净占比为净额占成交额的百分比
+///
+pub struct MoneyFlowColumns;
+
+impl MoneyFlowColumns {
+    pub const SPEC: [(&'static str, &'static str); 18] = [
+        ("代码", "code"),
+        ("名称", "name"),
+        ("日期", "date"),
+        ("最新价", "price"),
+        ("收盘价", "close"),
+        ("涨跌幅", "change_pct"),
+        ("主力净流入-净额", "main_net_inflow"),
+        ("主力净流入-净占比", "main_net_pct"),
+        ("超大单净流入-净额", "super_large_net_inflow"),
+        ("超大单净流入-净占比", "super_large_net_pct"),
+        ("大单净流入-净额", "large_net_inflow"),
+        ("大单净流入-净占比", "large_net_pct"),
+        ("中单净流入-净额", "medium_net_inflow"),
+        ("中单净流入-净占比", "medium_net_pct"),
+        ("小单净流入-净额", "small_net_inflow"),
+        ("小单净流入-净占比", "small_net_pct"),
+        ("symbol", "symbol"),
+        ("更新时间", "update_time"),
+    ];
+
+    ///
+    /// 中文列名转为英文列名，不在定义中的列保持不变
+    ///
+    pub fn english(df: &DataFrame) -> anyhow::Result<DataFrame> {
+        let mut df = df.clone();
+        for (zh, en) in MoneyFlowColumns::SPEC {
+            if df.column(zh).is_ok() {
+                df.rename(zh, en)?;
+            }
+        }
+
+        Ok(df)
+    }
+}
+
+///
+/// 资金流向排序依据
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FlowOrder {
+    /// 主力净流入
+    Main,
+    /// 超大单净流入
+    SuperLarge,
+    /// 大单净流入
+    Large,
+    /// 中单净流入
+    Medium,
+    /// 小单净流入
+    Small,
+}
+
+impl FlowOrder {
+    fn fid(&self) -> &str {
+        match self {
+            FlowOrder::Main => "f62",
+            FlowOrder::SuperLarge => "f66",
+            FlowOrder::Large => "f72",
+            FlowOrder::Medium => "f78",
+            FlowOrder::Small => "f84",
+        }
+    }
+}
+
+///
+/// 东方财富网-数据中心-资金流向-个股资金流排名(今日)
+/// https://data.eastmoney.com/zjlx/detail.html
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyMoneyFlowRankDataSource {
+    /// 按该类订单净流入额倒序
+    pub order: FlowOrder,
+}
+
+impl HttpSource for EastmoneyMoneyFlowRankDataSource {
+    fn request(&self) -> Request {
+        EastmoneyClist::request_sorted(
+            EastmoneyClist::A_SHARES,
+            "f2,f3,f12,f14,f62,f66,f69,f72,f75,f78,f81,f84,f87,f124,f184",
+            self.order.fid(),
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyMoneyFlowRankDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyClist::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("f12", "代码"),
+            ("f14", "名称"),
+            ("f2", "最新价"),
+            ("f3", "涨跌幅"),
+            ("f62", "主力净流入-净额"),
+            ("f184", "主力净流入-净占比"),
+            ("f66", "超大单净流入-净额"),
+            ("f69", "超大单净流入-净占比"),
+            ("f72", "大单净流入-净额"),
+            ("f75", "大单净流入-净占比"),
+            ("f78", "中单净流入-净额"),
+            ("f81", "中单净流入-净占比"),
+            ("f84", "小单净流入-净额"),
+            ("f87", "小单净流入-净占比"),
+            ("f124", "更新时间"),
+            ("f12", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("最新价".to_string(), DataType::Float64);
+        schema.with_column("涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("主力净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("主力净流入-净占比".to_string(), DataType::Float64);
+        schema.with_column("超大单净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("超大单净流入-净占比".to_string(), DataType::Float64);
+        schema.with_column("大单净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("大单净流入-净占比".to_string(), DataType::Float64);
+        schema.with_column("中单净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("中单净流入-净占比".to_string(), DataType::Float64);
+        schema.with_column("小单净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("小单净流入-净占比".to_string(), DataType::Float64);
+        schema.with_column("更新时间".to_string(), DataType::Int64);
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyMoneyFlowRankDataSource {
+    ///
+    /// 沪深京A股今日资金流向排名，更新时间为秒级时间戳
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+///
+/// 东方财富网-个股资金流向-日K，只提供最近约100个交易日
+/// https://data.eastmoney.com/zjlx/600519.html
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyMoneyFlowHistoryDataSource {}
+
+impl EastmoneyMoneyFlowHistoryDataSource {
+    ///
+    /// 日K字段: 日期,主力净额,小单净额,中单净额,大单净额,超大单净额,主力净占比,小单净占比,
+    /// 中单净占比,大单净占比,超大单净占比,收盘价,涨跌幅
+    ///
+    const FIELDS: [&'static str; 13] = [
+        "f51", "f52", "f53", "f54", "f55", "f56", "f57", "f58", "f59", "f60", "f61", "f62", "f63",
+    ];
+
+    fn request(secid: &str) -> Request {
+        let url = Url::parse_with_params(
+            "http://push2his.eastmoney.com/api/qt/stock/fflow/daykline/get",
+            &[
+                ("lmt", "0"),
+                ("klt", "101"),
+                ("secid", secid),
+                ("fields1", "f1,f2,f3,f7"),
+                (
+                    "fields2",
+                    &EastmoneyMoneyFlowHistoryDataSource::FIELDS.join(","),
+                ),
+                ("ut", "b2884a393a59ad64002292a3e90d46a5"),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+}
+
+impl DataResultFormat for EastmoneyMoneyFlowHistoryDataSource {
+    ///
+    /// 解析响应中的 data.klines，列名为 code,name,f51..f63
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(&body)?;
+
+            let klines: Vec<Vec<&str>> = match json.pointer("/data/klines") {
+                Some(Value::Array(klines)) => klines
+                    .iter()
+                    .filter_map(|k| k.as_str())
+                    .map(|k| k.split(',').collect())
+                    .collect(),
+                _ => vec![],
+            };
+            if klines.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let code = json.pointer("/data/code").and_then(|v| v.as_str());
+            let name = json.pointer("/data/name").and_then(|v| v.as_str());
+
+            let fields = EastmoneyMoneyFlowHistoryDataSource::FIELDS;
+            let mut columns = vec![
+                Series::new("code", vec![code; klines.len()]),
+                Series::new("name", vec![name; klines.len()]),
+                Series::new(fields[0], klines.iter().map(|k| k[0]).collect::<Vec<_>>()),
+            ];
+            for (i, field) in fields.iter().enumerate().skip(1) {
+                let values: Vec<Option<f64>> = klines
+                    .iter()
+                    .map(|k| k.get(i).and_then(|v| v.parse().ok()))
+                    .collect();
+                columns.push(Series::new(field, values));
+            }
+
+            return Ok(DataResult::new("".to_string(), DataFrame::new(columns)?));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("code", "代码"),
+            ("name", "名称"),
+            ("f51", "日期"),
+            ("f62", "收盘价"),
+            ("f63", "涨跌幅"),
+            ("f52", "主力净流入-净额"),
+            ("f57", "主力净流入-净占比"),
+            ("f56", "超大单净流入-净额"),
+            ("f61", "超大单净流入-净占比"),
+            ("f55", "大单净流入-净额"),
+            ("f60", "大单净流入-净占比"),
+            ("f54", "中单净流入-净额"),
+            ("f59", "中单净流入-净占比"),
+            ("f53", "小单净流入-净额"),
+            ("f58", "小单净流入-净占比"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("日期".to_string(), DataType::Utf8);
+        schema.with_column("收盘价".to_string(), DataType::Float64);
+        schema.with_column("涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("主力净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("主力净流入-净占比".to_string(), DataType::Float64);
+        schema.with_column("超大单净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("超大单净流入-净占比".to_string(), DataType::Float64);
+        schema.with_column("大单净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("大单净流入-净占比".to_string(), DataType::Float64);
+        schema.with_column("中单净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("中单净流入-净占比".to_string(), DataType::Float64);
+        schema.with_column("小单净流入-净额".to_string(), DataType::Float64);
+        schema.with_column("小单净流入-净占比".to_string(), DataType::Float64);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyMoneyFlowHistoryDataSource {
+    ///
+    /// 个股 [start, end] 期间每日资金流向，market 为 sh/sz/bj 可传空，symbol 为股票代码，如 600519
+    ///
+    async fn history_daily(
+        self,
+        market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let secid = EastmoneyClist::secid(&format!("{}{}", market, symbol));
+        let result =
+            HttpClient::exec_by_cache(EastmoneyMoneyFlowHistoryDataSource::request(&secid), self)
+                .await?;
+
+        let Some(df) = result.data.filter(|df| df.height() > 0) else {
+            return Ok(DataResult::new(symbol.to_string(), DataFrame::empty()));
+        };
+        let df = df
+            .lazy()
+            .filter(
+                col("日期")
+                    .gt_eq(lit(start.format("%Y-%m-%d").to_string()))
+                    .and(col("日期").lt_eq(lit(end.format("%Y-%m-%d").to_string()))),
+            )
+            .collect()?;
+
+        Ok(DataResult::new(symbol.to_string(), df))
+    }
+}
@@ -0,0 +1,352 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::{Duration, Local, NaiveDate},
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::{Method, Request, Url};
+use serde_json::{json, Value};
+
+use crate::{
+    utils::{HttpClient, JsonUtils},
+    DataResult, DataResultFormat, HistoryData, HttpSource,
+};
+
+///
+/// 东方财富网-数据中心-公告大全-个股公告
+/// https://data.eastmoney.com/notices/
+///
+/// 公告日期为公告所属日期，发布时间为公告实际披露时间(精确到秒)，
+/// 盘后披露的公告其影响应从下一交易日起计算
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyAnnouncementDataSource {}
+
+impl EastmoneyAnnouncementDataSource {
+    /// 每页条数
+    const PAGE_SIZE: usize = 100;
+
+    fn request_page(symbol: &str, start: NaiveDate, end: NaiveDate, page: usize) -> Request {
+        let url = Url::parse_with_params(
+            "https://np-anotice-stock.eastmoney.com/api/security/ann",
+            &[
+                ("sr", "-1"),
+                (
+                    "page_size",
+                    &EastmoneyAnnouncementDataSource::PAGE_SIZE.to_string(),
+                ),
+                ("page_index", &page.to_string()),
+                ("ann_type", "A"),
+                ("client_source", "web"),
+                ("stock_list", code(symbol)),
+                ("f_node", "0"),
+                ("s_node", "0"),
+                ("begin_time", &start.format("%Y-%m-%d").to_string()),
+                ("end_time", &end.format("%Y-%m-%d").to_string()),
+            ],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+}
+
+impl HttpSource for EastmoneyAnnouncementDataSource {
+    fn request(&self) -> Request {
+        let today = Local::now().date_naive();
+        EastmoneyAnnouncementDataSource::request_page("", today, today, 1)
+    }
+}
+
+impl DataResultFormat for EastmoneyAnnouncementDataSource {
+    ///
+    /// data.list 每条公告可关联多只股票、多个公告类型，按股票展开，类型以逗号连接
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(&body)?;
+
+            let (mut codes, mut names, mut titles, mut types) = (vec![], vec![], vec![], vec![]);
+            let (mut dates, mut times, mut urls) = (vec![], vec![], vec![]);
+            if let Some(Value::Array(list)) = json.pointer("/data/list") {
+                for row in list {
+                    let text = |key: &str| row.get(key).and_then(|v| v.as_str()).unwrap_or("");
+                    let art_code = text("art_code");
+                    let column_names: Vec<&str> = match row.get("columns") {
+                        Some(Value::Array(columns)) => columns
+                            .iter()
+                            .filter_map(|c| c.get("column_name").and_then(|n| n.as_str()))
+                            .collect(),
+                        _ => vec![],
+                    };
+                    let Some(Value::Array(stocks)) = row.get("codes") else {
+                        continue;
+                    };
+                    for stock in stocks {
+                        let field = |key: &str| {
+                            stock
+                                .get(key)
+                                .and_then(|v| v.as_str())
+                                .unwrap_or("")
+                                .to_string()
+                        };
+                        codes.push(field("stock_code"));
+                        names.push(field("short_name"));
+                        titles.push(text("title").to_string());
+                        types.push(column_names.join(","));
+                        // 2023-10-31 00:00:00 -> 2023-10-31
+                        dates.push(text("notice_date").chars().take(10).collect::<String>());
+                        // 2023-10-30 17:43:12:370 -> 2023-10-30 17:43:12
+                        times.push(text("display_time").chars().take(19).collect::<String>());
+                        urls.push(format!("https://pdf.dfcfw.com/pdf/H2_{}_1.pdf", art_code));
+                    }
+                }
+            }
+            if codes.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let df = DataFrame::new(vec![
+                Series::new("code", codes),
+                Series::new("name", names),
+                Series::new("title", titles),
+                Series::new("type", types),
+                Series::new("date", dates),
+                Series::new("time", times),
+                Series::new("url", urls),
+            ])?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("code", "代码"),
+            ("name", "名称"),
+            ("title", "公告标题"),
+            ("type", "公告类型"),
+            ("date", "公告日期"),
+            ("time", "发布时间"),
+            ("url", "公告链接"),
+            ("code", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            schema.with_column(name.to_string(), DataType::Utf8);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyAnnouncementDataSource {
+    ///
+    /// [start, end] 期间的个股公告，market 不区分传空即可，symbol 为股票代码，传空时返回全部A股公告
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        // 展开后的行数不少于公告条数，不足一页即为最后一页
+        let data_frame =
+            HttpClient::exec_by_pages(&self, EastmoneyAnnouncementDataSource::PAGE_SIZE, |page| {
+                EastmoneyAnnouncementDataSource::request_page(symbol, start, end, page)
+            })
+            .await?;
+
+        // 公告同时关联其他股票时仅保留所查询股票
+        let df = match code(symbol) {
+            "" => data_frame,
+            code => data_frame
+                .lazy()
+                .filter(col("代码").eq(lit(code)))
+                .collect()?,
+        };
+
+        Ok(DataResult::new(symbol.to_string(), df))
+    }
+}
+
+///
+/// 东方财富网-搜索-个股资讯
+/// https://so.eastmoney.com/news/s?keyword=600000
+///
+/// 返回资讯标题、摘要、来源及发布时间，接口不支持按日期查询，按发布时间倒序分页
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyNewsDataSource {}
+
+impl EastmoneyNewsDataSource {
+    /// 每页条数，接口最多返回100条
+    const PAGE_SIZE: usize = 100;
+
+    fn request_page(symbol: &str, page: usize) -> Request {
+        let param = json!({
+            "uid": "",
+            "keyword": code(symbol),
+            "type": ["cmsArticleWebOld"],
+            "client": "web",
+            "clientType": "web",
+            "clientVersion": "curr",
+            "param": {
+                "cmsArticleWebOld": {
+                    "searchScope": "default",
+                    "sort": "time",
+                    "pageIndex": page,
+                    "pageSize": EastmoneyNewsDataSource::PAGE_SIZE,
+                    "preTag": "",
+                    "postTag": "",
+                }
+            }
+        });
+        let url = Url::parse_with_params(
+            "https://search-api-web.eastmoney.com/search/jsonp",
+            &[("cb", "jQuery"), ("param", &param.to_string())],
+        )
+        .unwrap();
+
+        Request::new(Method::GET, url)
+    }
+}
+
+impl HttpSource for EastmoneyNewsDataSource {
+    fn request(&self) -> Request {
+        EastmoneyNewsDataSource::request_page("600000", 1)
+    }
+}
+
+impl DataResultFormat for EastmoneyNewsDataSource {
+    ///
+    /// jQuery({"result":{"cmsArticleWebOld":[{"date":..,"title":..}]}})，标题可能带 <em> 高亮标签
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(JsonUtils::unwrap_jsonp(&body))?;
+
+            let rows = match json.pointer("/result/cmsArticleWebOld") {
+                Some(Value::Array(rows)) if !rows.is_empty() => rows.clone(),
+                _ => return Ok(DataResult::new("".to_string(), DataFrame::empty())),
+            };
+
+            let field = |key: &str| -> Vec<String> {
+                rows.iter()
+                    .map(|row| {
+                        let s = row.get(key).and_then(|v| v.as_str()).unwrap_or("");
+                        s.replace("<em>", "")
+                            .replace("</em>", "")
+                            .trim()
+                            .to_string()
+                    })
+                    .collect()
+            };
+            let df = DataFrame::new(vec![
+                Series::new("title", field("title")),
+                Series::new("content", field("content")),
+                Series::new("time", field("date")),
+                Series::new("media", field("mediaName")),
+                Series::new("url", field("url")),
+            ])?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("title", "标题"),
+            ("content", "摘要"),
+            ("time", "发布时间"),
+            ("media", "来源"),
+            ("url", "链接"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            schema.with_column(name.to_string(), DataType::Utf8);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyNewsDataSource {
+    ///
+    /// [start, end] 期间的个股资讯，market 不区分传空即可，symbol 为股票代码
+    ///
+    /// 按发布时间倒序逐页获取，直到越过 start 或没有更早的资讯，再按发布时间过滤
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        // 发布时间如 2023-10-31 08:05:20，按字符串比较
+        let start_time = start.format("%Y-%m-%d").to_string();
+        let mut data_frame = self.col_schema().as_ref().map(DataFrame::from).unwrap();
+
+        for page in 1..=HttpClient::MAX_PAGES {
+            let request = EastmoneyNewsDataSource::request_page(symbol, page);
+            let result = HttpClient::exec_by_cache(request, self.clone()).await?;
+            let Some(df) = result.data.filter(|df| df.height() > 0) else {
+                break;
+            };
+
+            let oldest = df
+                .column("发布时间")?
+                .utf8()?
+                .into_iter()
+                .flatten()
+                .min()
+                .map(str::to_string);
+            data_frame.vstack_mut(&df)?;
+
+            let passed = oldest.map_or(true, |t| t < start_time);
+            if passed || df.height() < EastmoneyNewsDataSource::PAGE_SIZE {
+                break;
+            }
+        }
+
+        let df = data_frame
+            .lazy()
+            .filter(
+                col("发布时间")
+                    .gt_eq(lit(start_time))
+                    .and(col("发布时间").lt(lit(
+                        (end + Duration::days(1)).format("%Y-%m-%d").to_string(),
+                    ))),
+            )
+            .sort_by_exprs([col("发布时间")], [true], false)
+            .collect()?;
+
+        Ok(DataResult::new(symbol.to_string(), df))
+    }
+}
+
+///
+/// 去掉市场前缀，sh600000 -> 600000
+///
+fn code(symbol: &str) -> &str {
+    symbol.trim_start_matches(|c: char| c.is_ascii_alphabetic())
+}
@@ -0,0 +1,283 @@
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    lazy::dsl::lit,
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series},
+};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::eastmoney::{EastmoneyClist, EastmoneyKline},
+    utils::HttpClient,
+    DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 境外市场
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OverseasMarket {
+    /// 港股，东方财富市场代码 116
+    Hk,
+    /// 美股，东方财富市场代码 105(纳斯达克)、106(纽交所)、107(美交所)
+    Us,
+}
+
+impl OverseasMarket {
+    fn fs(&self) -> &str {
+        match self {
+            OverseasMarket::Hk => "m:116 t:3,m:116 t:4,m:116 t:1,m:116 t:2",
+            OverseasMarket::Us => "m:105,m:106,m:107",
+        }
+    }
+
+    ///
+    /// 报价币种
+    ///
+    pub fn currency(&self) -> &str {
+        match self {
+            OverseasMarket::Hk => "HKD",
+            OverseasMarket::Us => "USD",
+        }
+    }
+}
+
+///
+/// 东方财富网-行情中心-港股、美股-实时行情
+/// http://quote.eastmoney.com/center/gridlist.html#hk_stocks
+/// http://quote.eastmoney.com/center/gridlist.html#us_stocks
+///
+/// 价格、成交额、市值均为 币种 列对应的原币种
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyOverseasSpotDataSource {
+    pub market: OverseasMarket,
+}
+
+impl EastmoneyOverseasSpotDataSource {
+    ///
+    /// 由实时行情的 secid 列查找代码所在市场，如 AAPL -> 105.AAPL，BRK_A -> 106.BRK_A
+    ///
+    async fn secid(&self, symbol: &str) -> anyhow::Result<String> {
+        let df = self.real_time_data().await?.data.unwrap_or_default();
+        if df.height() == 0 {
+            return Err(anyhow!("实时行情为空，无法确定 {} 所在市场", symbol));
+        }
+
+        let codes = df.column("代码")?.utf8()?;
+        let secids = df.column("secid")?.utf8()?;
+        let secid = codes
+            .into_iter()
+            .zip(secids)
+            .find_map(|(code, secid)| secid.filter(|_| code == Some(symbol)))
+            .map(str::to_string);
+
+        secid.ok_or_else(|| anyhow!("未找到代码 {} 所在市场，请指定 market", symbol))
+    }
+}
+
+impl HttpSource for EastmoneyOverseasSpotDataSource {
+    fn request(&self) -> Request {
+        EastmoneyClist::request(
+            self.market.fs(),
+            "f2,f3,f4,f5,f6,f7,f8,f9,f12,f13,f14,f15,f16,f17,f18,f20,f23",
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyOverseasSpotDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyClist::to_dataframe(&body)?;
+            if df.height() == 0 {
+                return Ok(DataResult::new("".to_string(), df));
+            }
+
+            // 东方财富证券id(市场代码.代码)，用于查询日K线
+            let markets = df.column("f13")?.cast(&DataType::Utf8)?;
+            let codes = df.column("f12")?.cast(&DataType::Utf8)?;
+            let secids: Vec<Option<String>> = markets
+                .utf8()?
+                .into_iter()
+                .zip(codes.utf8()?)
+                .map(|(m, c)| Some(format!("{}.{}", m?, c?)))
+                .collect();
+
+            let df = df
+                .hstack(&[Series::new("secid", secids)])?
+                .lazy()
+                .with_column(lit(self.market.currency()).alias("currency"))
+                .collect()?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("f12", "代码"),
+            ("f14", "名称"),
+            ("f2", "最新价"),
+            ("f3", "涨跌幅"),
+            ("f4", "涨跌额"),
+            ("f5", "成交量"),
+            ("f6", "成交额"),
+            ("f7", "振幅"),
+            ("f15", "最高"),
+            ("f16", "最低"),
+            ("f17", "今开"),
+            ("f18", "昨收"),
+            ("f8", "换手率"),
+            ("f9", "市盈率"),
+            ("f23", "市净率"),
+            ("f20", "总市值"),
+            ("currency", "币种"),
+            ("secid", "secid"),
+            ("f12", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("最新价".to_string(), DataType::Float64);
+        schema.with_column("涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("涨跌额".to_string(), DataType::Float64);
+        schema.with_column("成交量".to_string(), DataType::Float64);
+        schema.with_column("成交额".to_string(), DataType::Float64);
+        schema.with_column("振幅".to_string(), DataType::Float64);
+        schema.with_column("最高".to_string(), DataType::Float64);
+        schema.with_column("最低".to_string(), DataType::Float64);
+        schema.with_column("今开".to_string(), DataType::Float64);
+        schema.with_column("昨收".to_string(), DataType::Float64);
+        schema.with_column("换手率".to_string(), DataType::Float64);
+        schema.with_column("市盈率".to_string(), DataType::Float64);
+        schema.with_column("市净率".to_string(), DataType::Float64);
+        schema.with_column("总市值".to_string(), DataType::Float64);
+        schema.with_column("币种".to_string(), DataType::Utf8);
+        schema.with_column("secid".to_string(), DataType::Utf8);
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyOverseasSpotDataSource {
+    ///
+    /// 港股或美股全部股票的实时行情
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyOverseasSpotDataSource {
+    ///
+    /// 港股、美股不复权日K线，market 为东方财富市场代码，美股为实时行情 secid 列中的
+    /// 105/106/107，传空时港股为 116，美股按实时行情查找代码所在市场，symbol 为代码，如 00700、AAPL
+    ///
+    async fn history_daily(
+        self,
+        market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let secid = match (market, &self.market) {
+            ("", OverseasMarket::Hk) => format!("116.{}", symbol),
+            ("", OverseasMarket::Us) => self.secid(symbol).await?,
+            (market, _) => format!("{}.{}", market, symbol),
+        };
+
+        HttpClient::exec_by_cache(
+            EastmoneyKline::request(&secid, "0", start, end),
+            EastmoneyKline,
+        )
+        .await
+    }
+}
+
+///
+/// 东方财富网-行情中心-AH股比价-实时行情
+/// http://quote.eastmoney.com/center/gridlist.html#ah_comparison
+///
+/// 比价 = A股价格 / (H股价格 * 港元兑人民币汇率)，溢价率 = (比价 - 1) * 100
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyAhPremiumDataSource {}
+
+impl HttpSource for EastmoneyAhPremiumDataSource {
+    fn request(&self) -> Request {
+        EastmoneyClist::request("b:DLMK0101", "f2,f3,f12,f14,f186,f187,f188,f189,f191,f193")
+    }
+}
+
+impl DataResultFormat for EastmoneyAhPremiumDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyClist::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("f193", "名称"),
+            ("f12", "H股代码"),
+            ("f2", "H股最新价(港元)"),
+            ("f3", "H股涨跌幅"),
+            ("f191", "A股代码"),
+            ("f186", "A股最新价(人民币)"),
+            ("f187", "A股涨跌幅"),
+            ("f189", "比价"),
+            ("f188", "溢价率"),
+            ("f191", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("H股代码".to_string(), DataType::Utf8);
+        schema.with_column("H股最新价(港元)".to_string(), DataType::Float64);
+        schema.with_column("H股涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("A股代码".to_string(), DataType::Utf8);
+        schema.with_column("A股最新价(人民币)".to_string(), DataType::Float64);
+        schema.with_column("A股涨跌幅".to_string(), DataType::Float64);
+        schema.with_column("比价".to_string(), DataType::Float64);
+        schema.with_column("溢价率".to_string(), DataType::Float64);
+        schema.with_column("symbol".to_string(), DataType::Utf8);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyAhPremiumDataSource {
+    ///
+    /// A+H 两地上市股票的实时比价及A股相对H股溢价率，symbol 为A股代码
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
@@ -0,0 +1,554 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    lazy::dsl::{col, lit},
+    prelude::{DataFrame, DataType, IntoLazy, NamedFrom, Schema, Series, UniqueKeepStrategy},
+};
+use reqwest::{
+    header::{HeaderValue, REFERER},
+    Method, Request, Url,
+};
+use serde_json::Value;
+
+use crate::{
+    sina::stock::eastmoney::{EastmoneyClist, EastmoneyDataCenter},
+    utils::{DateUtils, HtmlUtils, HttpClient, JsonUtils},
+    DataResult, DataResultFormat, HttpSource, RealTimeData,
+};
+
+///
+/// 证券列表
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecurityList {
+    /// 沪深京在市A股(东方财富)
+    Listed,
+    /// 上交所终止上市股票
+    DelistedSh,
+    /// 深交所终止上市股票
+    DelistedSz,
+}
+
+impl SecurityList {
+    ///
+    /// 每页条数，返回不足一页时结束分页
+    ///
+    fn page_size(&self) -> usize {
+        match self {
+            SecurityList::Listed => EastmoneyClist::PAGE_SIZE,
+            SecurityList::DelistedSh => 1000,
+            SecurityList::DelistedSz => 20,
+        }
+    }
+}
+
+///
+/// 证券列表: 代码、名称、交易所、板块、行业、上市日期、退市日期、总股本、流通股本
+///
+/// 在市股票来自东方财富行情列表，退市股票来自沪深交易所终止上市公司列表，
+/// 退市股票无行业及股本信息
+///
+#[derive(Clone, Debug)]
+pub struct SecurityListDataSource {
+    pub list: SecurityList,
+}
+
+///
+/// 证券列表中的一行，日期统一为 2001-08-27 格式
+///
+struct SecurityRow {
+    code: String,
+    name: Option<String>,
+    industry: Option<String>,
+    list_date: Option<String>,
+    delist_date: Option<String>,
+    total_shares: Option<f64>,
+    float_shares: Option<f64>,
+}
+
+impl SecurityListDataSource {
+    fn request_page(&self, page: usize) -> Request {
+        match self.list {
+            // 按代码排序分页，避免行情变化导致翻页时记录重复或遗漏
+            SecurityList::Listed => EastmoneyClist::request_page(
+                EastmoneyClist::A_SHARES,
+                "f12,f14,f26,f38,f39,f100",
+                "f12",
+                page,
+            ),
+            SecurityList::DelistedSh => {
+                let page = page.to_string();
+                let url = Url::parse_with_params(
+                    "https://query.sse.com.cn/commonQuery.do",
+                    &[
+                        ("sqlId", "COMMON_SSE_CP_GPJCTPZ_GPLB_GP_L"),
+                        ("isPagination", "true"),
+                        ("STOCK_TYPE", "1"),
+                        ("COMPANY_STATUS", "3"),
+                        ("type", "inParams"),
+                        ("pageHelp.cacheSize", "1"),
+                        ("pageHelp.pageSize", "1000"),
+                        ("pageHelp.pageNo", &page),
+                        ("pageHelp.beginPage", &page),
+                        ("pageHelp.endPage", &page),
+                    ],
+                )
+                .unwrap();
+
+                // 接口校验 referer
+                let mut request = Request::new(Method::GET, url);
+                request
+                    .headers_mut()
+                    .insert(REFERER, HeaderValue::from_static("https://www.sse.com.cn/"));
+
+                request
+            }
+            SecurityList::DelistedSz => {
+                let url = Url::parse_with_params(
+                    "https://www.szse.cn/api/report/ShowReport/data",
+                    &[
+                        ("SHOWTYPE", "JSON"),
+                        ("CATALOGID", "1793_ssgs"),
+                        ("TABKEY", "tab2"),
+                        ("PAGENO", &page.to_string()),
+                    ],
+                )
+                .unwrap();
+
+                Request::new(Method::GET, url)
+            }
+        }
+    }
+
+    fn parse_rows(&self, body: &str) -> anyhow::Result<Vec<SecurityRow>> {
+        let json: Value = serde_json::from_str(body)?;
+
+        let rows = match self.list {
+            SecurityList::Listed => {
+                let rows: Vec<&Value> = match json.pointer("/data/diff") {
+                    Some(Value::Array(rows)) => rows.iter().collect(),
+                    Some(Value::Object(rows)) => rows.values().collect(),
+                    _ => vec![],
+                };
+                rows.into_iter()
+                    .filter_map(|row| {
+                        Some(SecurityRow {
+                            code: text(row, "f12")?,
+                            name: text(row, "f14"),
+                            industry: text(row, "f100"),
+                            list_date: text(row, "f26").and_then(|d| DateUtils::normalize_ymd(&d)),
+                            delist_date: None,
+                            total_shares: JsonUtils::number(row.get("f38")),
+                            float_shares: JsonUtils::number(row.get("f39")),
+                        })
+                    })
+                    .collect()
+            }
+            SecurityList::DelistedSh => match json.pointer("/result") {
+                Some(Value::Array(rows)) => rows
+                    .iter()
+                    .filter_map(|row| {
+                        Some(SecurityRow {
+                            code: text(row, "COMPANY_CODE")?,
+                            name: text(row, "COMPANY_ABBR")
+                                .or_else(|| text(row, "SECURITY_ABBR_A")),
+                            industry: None,
+                            list_date: text(row, "LIST_DATE")
+                                .and_then(|d| DateUtils::normalize_ymd(&d)),
+                            delist_date: text(row, "DELIST_DATE")
+                                .and_then(|d| DateUtils::normalize_ymd(&d)),
+                            total_shares: None,
+                            float_shares: None,
+                        })
+                    })
+                    .collect(),
+                _ => vec![],
+            },
+            SecurityList::DelistedSz => {
+                // 返回各 tab 的数组，取终止上市 tab2
+                let tabs = json.as_array().cloned().unwrap_or_default();
+                let tab = tabs
+                    .iter()
+                    .find(|t| {
+                        t.pointer("/metadata/tabkey").and_then(|k| k.as_str()) == Some("tab2")
+                    })
+                    .or_else(|| tabs.first());
+                match tab.and_then(|t| t.get("data")) {
+                    Some(Value::Array(rows)) => rows
+                        .iter()
+                        .filter_map(|row| {
+                            Some(SecurityRow {
+                                code: text(row, "zqdm").map(|c| HtmlUtils::strip_tags(&c))?,
+                                name: text(row, "zqjc").map(|n| HtmlUtils::strip_tags(&n)),
+                                industry: None,
+                                list_date: text(row, "ssrq")
+                                    .and_then(|d| DateUtils::normalize_ymd(&d)),
+                                delist_date: text(row, "zzrq")
+                                    .and_then(|d| DateUtils::normalize_ymd(&d)),
+                                total_shares: None,
+                                float_shares: None,
+                            })
+                        })
+                        .collect(),
+                    _ => vec![],
+                }
+            }
+        };
+
+        Ok(rows)
+    }
+
+    ///
+    /// 获取全部分页
+    ///
+    async fn fetch(&self) -> anyhow::Result<DataFrame> {
+        HttpClient::exec_by_pages(self, self.list.page_size(), |page| self.request_page(page)).await
+    }
+}
+
+impl HttpSource for SecurityListDataSource {
+    fn request(&self) -> Request {
+        self.request_page(1)
+    }
+}
+
+impl DataResultFormat for SecurityListDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let rows = self.parse_rows(&body)?;
+            if rows.is_empty() {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            }
+
+            let df = DataFrame::new(vec![
+                Series::new(
+                    "code",
+                    rows.iter().map(|r| r.code.as_str()).collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "name",
+                    rows.iter().map(|r| r.name.as_deref()).collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "exchange",
+                    rows.iter()
+                        .map(|r| SecurityMaster::exchange(&r.code))
+                        .collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "board",
+                    rows.iter()
+                        .map(|r| SecurityMaster::board(&r.code))
+                        .collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "industry",
+                    rows.iter()
+                        .map(|r| r.industry.as_deref())
+                        .collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "list_date",
+                    rows.iter()
+                        .map(|r| r.list_date.as_deref())
+                        .collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "delist_date",
+                    rows.iter()
+                        .map(|r| r.delist_date.as_deref())
+                        .collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "total_shares",
+                    rows.iter().map(|r| r.total_shares).collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "float_shares",
+                    rows.iter().map(|r| r.float_shares).collect::<Vec<_>>(),
+                ),
+            ])?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("code", "代码"),
+            ("name", "名称"),
+            ("exchange", "交易所"),
+            ("board", "板块"),
+            ("industry", "行业"),
+            ("list_date", "上市日期"),
+            ("delist_date", "退市日期"),
+            ("total_shares", "总股本"),
+            ("float_shares", "流通股本"),
+            ("code", "symbol"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "总股本" | "流通股本" => DataType::Float64,
+                _ => DataType::Utf8,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for SecurityListDataSource {
+    ///
+    /// 全部证券列表，按当日缓存
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let df = self.fetch().await?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+///
+/// 东方财富-F10-公司概况-证券简称更名历史
+/// https://emweb.securities.eastmoney.com/PC_HSF10/CompanySurvey/Index?code=SH600519
+///
+/// 接口仅提供曾用名序列，不含变更日期，序号越大越新，最后一条为当前简称
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyNameChangeDataSource {
+    /// 股票代码，如 600519 或 sh600519
+    pub symbol: String,
+}
+
+impl EastmoneyNameChangeDataSource {
+    fn code(&self) -> &str {
+        self.symbol
+            .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+    }
+}
+
+impl HttpSource for EastmoneyNameChangeDataSource {
+    fn request(&self) -> Request {
+        let code = self.code();
+        EastmoneyDataCenter::request(
+            "RPT_F10_BASIC_ORGINFO",
+            &format!("(SECUCODE=\"{}.{}\")", code, SecurityMaster::exchange(code)),
+            "SECUCODE",
+            "1",
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyNameChangeDataSource {
+    ///
+    /// 曾用名形如 G茅台→贵州茅台，拆分为逐行记录并补充当前简称
+    ///
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let json: Value = serde_json::from_str(&body)?;
+            let Some(row) = json.pointer("/result/data/0") else {
+                return Ok(DataResult::new("".to_string(), DataFrame::empty()));
+            };
+
+            let mut names: Vec<String> = text(row, "FORMERNAME")
+                .map(|n| {
+                    n.split(['→', ',', '，'])
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+            if let Some(current) = text(row, "SECURITY_NAME_ABBR") {
+                if names.last() != Some(&current) {
+                    names.push(current);
+                }
+            }
+            names.dedup();
+
+            let code = text(row, "SECURITY_CODE").unwrap_or_else(|| self.code().to_string());
+            let df = DataFrame::new(vec![
+                Series::new("code", vec![code.as_str(); names.len()]),
+                Series::new("seq", (1..=names.len() as i64).collect::<Vec<_>>()),
+                Series::new("name", names.iter().map(String::as_str).collect::<Vec<_>>()),
+                Series::new(
+                    "st",
+                    names.iter().map(|n| n.contains("ST")).collect::<Vec<_>>(),
+                ),
+            ])?;
+
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let ca = vec![
+            ("code", "代码"),
+            ("seq", "序号"),
+            ("name", "名称"),
+            ("st", "ST"),
+        ];
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        schema.with_column("代码".to_string(), DataType::Utf8);
+        schema.with_column("序号".to_string(), DataType::Int64);
+        schema.with_column("名称".to_string(), DataType::Utf8);
+        schema.with_column("ST".to_string(), DataType::Boolean);
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyNameChangeDataSource {
+    ///
+    /// 个股证券简称更名历史，按先后顺序
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        HttpClient::exec_by_cache(self.request(), self.clone()).await
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+///
+/// 证券主表，包含在市及已退市股票，回测时按日期取股票池以避免幸存者偏差
+///
+/// 各证券列表按当日缓存，同一代码同时出现在在市及退市列表时(如退市整理期)以退市记录为准
+///
+#[derive(Clone, Debug)]
+pub struct SecurityMaster {
+    pub data: DataFrame,
+}
+
+impl SecurityMaster {
+    ///
+    /// 加载在市及沪深退市证券列表
+    ///
+    pub async fn load() -> anyhow::Result<SecurityMaster> {
+        let mut data = SecurityListDataSource {
+            list: SecurityList::Listed,
+        }
+        .col_schema()
+        .as_ref()
+        .map(DataFrame::from)
+        .unwrap();
+
+        for list in [
+            SecurityList::DelistedSh,
+            SecurityList::DelistedSz,
+            SecurityList::Listed,
+        ] {
+            let data_source = SecurityListDataSource { list };
+            if let Some(df) = data_source.real_time_data().await?.data {
+                data.vstack_mut(&df)?;
+            }
+        }
+
+        Ok(SecurityMaster::from(data))
+    }
+
+    ///
+    /// 按股票代码查询，symbol 可带市场前缀，如 sh600519
+    ///
+    pub fn get(&self, symbol: &str) -> anyhow::Result<DataFrame> {
+        let code = symbol.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+        let df = self
+            .data
+            .clone()
+            .lazy()
+            .filter(col("代码").eq(lit(code)))
+            .collect()?;
+
+        Ok(df)
+    }
+
+    ///
+    /// 时点股票池: date 当日已上市且未退市的股票，包含此后退市的股票
+    ///
+    pub fn universe(&self, date: NaiveDate) -> anyhow::Result<DataFrame> {
+        let date = date.format("%Y-%m-%d").to_string();
+        let df = self
+            .data
+            .clone()
+            .lazy()
+            .filter(
+                col("上市日期").lt_eq(lit(date.as_str())).and(
+                    col("退市日期")
+                        .is_null()
+                        .or(col("退市日期").gt(lit(date.as_str()))),
+                ),
+            )
+            .collect()?;
+
+        Ok(df)
+    }
+
+    ///
+    /// 股票代码所属交易所: SH 上交所, SZ 深交所, BJ 北交所
+    ///
+    pub fn exchange(code: &str) -> &'static str {
+        match code {
+            c if c.starts_with(['4', '8']) || c.starts_with("92") => "BJ",
+            c if c.starts_with(['5', '6', '9']) => "SH",
+            _ => "SZ",
+        }
+    }
+
+    ///
+    /// 股票代码所属板块，原中小板(002)已并入深市主板
+    ///
+    pub fn board(code: &str) -> &'static str {
+        match code {
+            c if c.starts_with("688") || c.starts_with("689") => "科创板",
+            c if c.starts_with("300") || c.starts_with("301") => "创业板",
+            c if c.starts_with("900") || c.starts_with("200") => "B股",
+            c if c.starts_with(['4', '8']) || c.starts_with("92") => "北交所",
+            _ => "主板",
+        }
+    }
+}
+
+impl From<DataFrame> for SecurityMaster {
+    fn from(data: DataFrame) -> Self {
+        // 退市记录在前，按代码去重保留退市日期
+        let data = data
+            .clone()
+            .lazy()
+            .unique_stable(Some(vec!["代码".to_string()]), UniqueKeepStrategy::First)
+            .collect()
+            .unwrap_or(data);
+
+        SecurityMaster { data }
+    }
+}
+
+fn text(row: &Value, key: &str) -> Option<String> {
+    match row.get(key)? {
+        Value::String(s) if !s.trim().is_empty() && s != "-" => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
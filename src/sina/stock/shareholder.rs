@@ -0,0 +1,267 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use polars::{
+    export::chrono::NaiveDate,
+    prelude::{DataFrame, DataType, Schema},
+};
+use reqwest::Request;
+
+use crate::{
+    sina::stock::{eastmoney::EastmoneyDataCenter, security::SecurityMaster},
+    utils::HttpClient,
+    DataResult, DataResultFormat, HistoryData, HttpSource, RealTimeData,
+};
+
+///
+/// 股东结构数据类型
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Shareholder {
+    /// 十大股东
+    TopHolders,
+    /// 十大流通股东
+    TopFloatHolders,
+    /// 股东户数及其变化
+    HolderCount,
+    /// 基金持股
+    FundHoldings,
+}
+
+impl Shareholder {
+    fn report_name(&self) -> &str {
+        match self {
+            Shareholder::TopHolders => "RPT_F10_EH_HOLDERS",
+            Shareholder::TopFloatHolders => "RPT_F10_EH_FREEHOLDERS",
+            Shareholder::HolderCount => "RPT_HOLDERNUM_DET",
+            Shareholder::FundHoldings => "RPT_MAINDATA_MAIN_POSITIONDETAILS",
+        }
+    }
+
+    ///
+    /// 报告期字段，基金持股为 REPORT_DATE，其余为 END_DATE
+    ///
+    fn report_date_column(&self) -> &str {
+        match self {
+            Shareholder::FundHoldings => "REPORT_DATE",
+            _ => "END_DATE",
+        }
+    }
+}
+
+///
+/// 东方财富数据中心-股东分析-十大股东、十大流通股东、股东户数、基金持股
+/// https://data.eastmoney.com/gdfx/
+///
+/// 报告期为定期报告期末(如 2023-09-30)，股东户数还包括公司公告的非定期统计日，
+/// 持股数单位为股，市值单位为元，比例单位为 %
+///
+#[derive(Clone, Debug)]
+pub struct EastmoneyShareholderDataSource {
+    pub holder: Shareholder,
+    /// 股票代码，如 600519，None 时返回全部A股
+    pub symbol: Option<String>,
+    /// 报告期，如 2023-09-30，None 时返回全部报告期
+    pub report_date: Option<NaiveDate>,
+}
+
+impl EastmoneyShareholderDataSource {
+    fn filter(&self, symbol: Option<&str>, date_filter: &str) -> String {
+        let security = match symbol {
+            Some(symbol) => {
+                let code = symbol.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+                match self.holder {
+                    // 十大股东按带交易所后缀的证券代码查询，如 600519.SH
+                    Shareholder::TopHolders | Shareholder::TopFloatHolders => {
+                        format!("(SECUCODE=\"{}.{}\")", code, SecurityMaster::exchange(code))
+                    }
+                    Shareholder::HolderCount => format!("(SECURITY_CODE=\"{}\")", code),
+                    Shareholder::FundHoldings => {
+                        format!("(SECURITY_CODE=\"{}\")(ORG_TYPE=\"1\")", code)
+                    }
+                }
+            }
+            None => match self.holder {
+                Shareholder::FundHoldings => "(ORG_TYPE=\"1\")".to_string(),
+                _ => "".to_string(),
+            },
+        };
+
+        format!("{}{}", security, date_filter)
+    }
+
+    fn report_date_filter(&self) -> String {
+        match self.report_date {
+            Some(date) => format!(
+                "({}='{}')",
+                self.holder.report_date_column(),
+                date.format("%Y-%m-%d")
+            ),
+            None => "".to_string(),
+        }
+    }
+
+    fn request_page(&self, filter: &str, page: usize) -> Request {
+        let (sort_columns, sort_types) = match self.holder {
+            Shareholder::TopHolders | Shareholder::TopFloatHolders => {
+                ("END_DATE,SECURITY_CODE,HOLDER_RANK", "-1,1,1")
+            }
+            Shareholder::HolderCount => ("END_DATE,SECURITY_CODE", "-1,1"),
+            Shareholder::FundHoldings => ("REPORT_DATE,HOLD_MARKET_CAP", "-1,-1"),
+        };
+
+        EastmoneyDataCenter::request_page(
+            self.holder.report_name(),
+            filter,
+            sort_columns,
+            sort_types,
+            page,
+        )
+    }
+
+    ///
+    /// 按 filter 获取全部分页
+    ///
+    async fn fetch(&self, filter: &str) -> anyhow::Result<DataFrame> {
+        HttpClient::exec_by_pages(self, EastmoneyDataCenter::PAGE_SIZE, |page| {
+            self.request_page(filter, page)
+        })
+        .await
+    }
+}
+
+impl HttpSource for EastmoneyShareholderDataSource {
+    fn request(&self) -> Request {
+        self.request_page(
+            &self.filter(self.symbol.as_deref(), &self.report_date_filter()),
+            1,
+        )
+    }
+}
+
+impl DataResultFormat for EastmoneyShareholderDataSource {
+    fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+        if let Some(body) = source {
+            let df = EastmoneyDataCenter::to_dataframe(&body)?;
+            return Ok(DataResult::new("".to_string(), df));
+        }
+
+        Ok(DataResult::default())
+    }
+
+    fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+        let mut ca = vec![
+            ("SECURITY_CODE", "代码"),
+            (self.holder.report_date_column(), "报告期"),
+        ];
+
+        match self.holder {
+            Shareholder::TopHolders => ca.extend([
+                ("HOLDER_RANK", "名次"),
+                ("HOLDER_NAME", "股东名称"),
+                ("SHARES_TYPE", "股份类型"),
+                ("HOLD_NUM", "持股数"),
+                ("HOLD_NUM_RATIO", "持股比例"),
+                ("HOLD_NUM_CHANGE", "增减"),
+                ("CHANGE_RATIO", "变动比例"),
+            ]),
+            Shareholder::TopFloatHolders => ca.extend([
+                ("HOLDER_RANK", "名次"),
+                ("HOLDER_NAME", "股东名称"),
+                ("HOLDER_NEWTYPE", "股东性质"),
+                ("SHARES_TYPE", "股份类型"),
+                ("HOLD_NUM", "持股数"),
+                ("FREE_HOLDNUM_RATIO", "占流通股比例"),
+                ("HOLD_NUM_CHANGE", "增减"),
+                ("CHANGE_RATIO", "变动比例"),
+            ]),
+            Shareholder::HolderCount => ca.extend([
+                ("SECURITY_NAME_ABBR", "名称"),
+                ("HOLD_NOTICE_DATE", "公告日期"),
+                ("PRE_END_DATE", "上期统计日"),
+                ("HOLDER_NUM", "股东户数"),
+                ("PRE_HOLDER_NUM", "上期股东户数"),
+                ("HOLDER_NUM_CHANGE", "户数增减"),
+                ("HOLDER_NUM_RATIO", "户数增减比例"),
+                ("INTERVAL_CHRATE", "区间涨跌幅"),
+                ("AVG_HOLD_NUM", "户均持股数"),
+                ("AVG_MARKET_CAP", "户均持股市值"),
+                ("TOTAL_MARKET_CAP", "总市值"),
+                ("TOTAL_A_SHARES", "总股本"),
+                ("CHANGE_REASON", "股本变动原因"),
+            ]),
+            Shareholder::FundHoldings => ca.extend([
+                ("SECURITY_NAME_ABBR", "名称"),
+                ("HOLDER_CODE", "基金代码"),
+                ("HOLDER_NAME", "基金名称"),
+                ("TOTAL_SHARES", "持股数"),
+                ("HOLD_MARKET_CAP", "持股市值"),
+                ("TOTALSHARES_RATIO", "占总股本比例"),
+                ("FREESHARES_RATIO", "占流通股比例"),
+            ]),
+        }
+        ca.push(("SECURITY_CODE", "symbol"));
+
+        Some(ca)
+    }
+
+    fn col_schema(&self) -> Option<Schema> {
+        let mut schema = Schema::new();
+        for (_, name) in self.col_alias().unwrap_or_default() {
+            let dtype = match name {
+                "名次" => DataType::Int64,
+                "代码" | "名称" | "报告期" | "股东名称" | "股东性质" | "股份类型" | "增减"
+                | "公告日期" | "上期统计日" | "股本变动原因" | "基金代码" | "基金名称"
+                | "symbol" => DataType::Utf8,
+                _ => DataType::Float64,
+            };
+            schema.with_column(name.to_string(), dtype);
+        }
+
+        Some(schema)
+    }
+}
+
+#[async_trait]
+impl RealTimeData for EastmoneyShareholderDataSource {
+    ///
+    /// 按股票代码和报告期查询股东结构，按报告期倒序
+    ///
+    async fn real_time_data(&self) -> Result<DataResult<DataFrame>, Error> {
+        let df = self
+            .fetch(&self.filter(self.symbol.as_deref(), &self.report_date_filter()))
+            .await?;
+
+        Ok(DataResult::new("".to_string(), df))
+    }
+
+    fn load_cached_schema(&self) -> Option<Schema> {
+        self.col_schema()
+    }
+}
+
+#[async_trait]
+impl HistoryData for EastmoneyShareholderDataSource {
+    ///
+    /// 报告期在 [start, end] 内的股东结构，market 可传空，symbol 为股票代码，传空时返回全部A股
+    ///
+    async fn history_daily(
+        self,
+        _market: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<DataResult<DataFrame>, Error> {
+        let column = self.holder.report_date_column();
+        let date_filter = format!(
+            "({}>='{}')({}<='{}')",
+            column,
+            start.format("%Y-%m-%d"),
+            column,
+            end.format("%Y-%m-%d")
+        );
+        let symbol = Some(symbol).filter(|s| !s.is_empty());
+        let df = self.fetch(&self.filter(symbol, &date_filter)).await?;
+
+        Ok(DataResult::new(symbol.unwrap_or("").to_string(), df))
+    }
+}
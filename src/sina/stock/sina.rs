@@ -4,7 +4,10 @@ use polars::{
     lazy::dsl::{col, Expr},
     prelude::{DataFrame, DataType, IntoLazy, Schema},
 };
-use reqwest::{Method, Request, Url};
+use reqwest::{
+    header::{HeaderValue, REFERER},
+    Method, Request, Url,
+};
 
 use crate::{
     utils::HttpClient, DataResult, DataResultFormat, HttpSource, RealTimeData, ResultCached,
@@ -167,3 +170,51 @@ impl RealTimeData for SinaIndexSpotDataSource {
         Some(schema)
     }
 }
+
+///
+/// 新浪财经实时行情接口 hq.sinajs.cn，返回 var hq_str_{代码}="字段1,字段2,...";
+///
+pub(crate) struct SinaHq;
+
+impl SinaHq {
+    ///
+    /// 构造行情请求，list 如 nf_RB2401、CON_OP_10006001
+    ///
+    pub(crate) fn request(list: &[String]) -> Request {
+        let url =
+            Url::parse_with_params("http://hq.sinajs.cn/", &[("list", list.join(","))]).unwrap();
+
+        // 接口校验 referer
+        let mut request = Request::new(Method::GET, url);
+        request.headers_mut().insert(
+            REFERER,
+            HeaderValue::from_static("https://finance.sina.com.cn/"),
+        );
+
+        request
+    }
+
+    ///
+    /// 解析为 (代码, 字段列表)，忽略无行情的代码
+    ///
+    pub(crate) fn parse(body: &str) -> Vec<(&str, Vec<&str>)> {
+        let mut rows = vec![];
+        for line in body.lines() {
+            let (Some(start), Some(end)) = (line.find("hq_str_"), line.find("=\"")) else {
+                continue;
+            };
+            let values = line[end + 2..].trim_end_matches(';').trim_end_matches('"');
+            if values.is_empty() {
+                tracing::debug!("无行情: {}", line);
+                continue;
+            }
+
+            rows.push((
+                &line[start + "hq_str_".len()..end],
+                values.split(',').collect(),
+            ));
+        }
+
+        rows
+    }
+}
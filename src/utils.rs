@@ -1,10 +1,11 @@
 use std::{fs, io::Error, path::Path};
 
-use crate::{const_vars, DataResult, DataResultFormat};
+use crate::{const_vars, DataResult, DataResultFormat, ResultCached};
 use mime::Mime;
-use polars::export::chrono::Local;
+use polars::export::chrono::{Datelike, Duration, Local, Months, NaiveDate};
 use polars::frame::DataFrame;
 use reqwest::{header, Request, Response};
+use serde_json::Value;
 
 ///
 /// http 请求工具类
@@ -30,6 +31,19 @@ impl HttpClient {
         Ok(call_back_body(body))
     }
 
+    ///
+    /// 执行http请求，返回响应文本
+    ///
+    pub async fn exec_text(request: Request) -> Result<String, anyhow::Error> {
+        tracing::debug!("request url: {:?}", request);
+
+        let http_client = reqwest::Client::new();
+
+        let response = http_client.execute(request).await?;
+
+        Ok(response.text().await?)
+    }
+
     ///
     /// 使用 DataResultFormat 对结果进行处理
     ///
@@ -59,6 +73,174 @@ impl HttpClient {
         Ok(DataResult::default())
     }
 
+    ///
+    /// 优先加载当日缓存，无缓存时请求数据、格式化并缓存
+    ///
+    pub async fn exec_by_cache(
+        request: Request,
+        format: impl DataResultFormat,
+    ) -> Result<DataResult<DataFrame>, anyhow::Error> {
+        let data_id = HttpClient::request_id(&request);
+        let schema = format.col_schema();
+
+        let result = DataResult::<DataFrame>::new(data_id.clone(), DataFrame::empty());
+        if result.is_cached() {
+            return result.load(schema);
+        }
+
+        let mut result = HttpClient::exec_and_format(request, format).await?;
+        // 无数据时不缓存，避免缓存错误页、验证码页等异常响应
+        if result.data.as_ref().is_some_and(|df| df.height() > 0) {
+            result.data_id = Some(data_id);
+            result.cache();
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// 优先加载永久缓存，无缓存时请求数据、格式化，有数据时永久缓存
+    ///
+    /// 仅用于已定稿、不再变化的历史数据，无数据(如非交易日或尚未披露)时不缓存
+    ///
+    pub async fn exec_by_permanent_cache(
+        request: Request,
+        format: impl DataResultFormat,
+    ) -> Result<DataResult<DataFrame>, anyhow::Error> {
+        let data_id = HttpClient::request_id(&request);
+        let schema = format.col_schema();
+
+        let result = DataResult::<DataFrame>::new(data_id.clone(), DataFrame::empty());
+        if result.is_cached_permanently() {
+            return result.load_permanently(schema);
+        }
+
+        let mut result = HttpClient::exec_and_format(request, format).await?;
+        if result.data.as_ref().is_some_and(|df| df.height() > 0) {
+            result.data_id = Some(data_id);
+            result.cache_permanently();
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// 优先加载永久缓存，无缓存时请求数据、格式化并永久缓存，无数据时同样缓存
+    ///
+    /// 仅用于已定稿日期的按日查询，响应正常但无数据即为非交易日，缓存后不再重复请求
+    ///
+    pub async fn exec_by_settled_cache(
+        request: Request,
+        format: impl DataResultFormat,
+    ) -> Result<DataResult<DataFrame>, anyhow::Error> {
+        let data_id = HttpClient::request_id(&request);
+        let schema = format.col_schema();
+
+        let result = DataResult::<DataFrame>::new(data_id.clone(), DataFrame::empty());
+        if result.is_cached_permanently() {
+            return result.load_permanently(schema);
+        }
+
+        let mut result = HttpClient::exec_and_format(request, format).await?;
+        result.data_id = Some(data_id);
+        result.cache_permanently();
+
+        Ok(result)
+    }
+
+    /// 分页请求的最大页数，超过时返回错误，避免接口异常时无限翻页
+    pub const MAX_PAGES: usize = 1000;
+
+    ///
+    /// 分页请求并合并结果，request_page 按页码(从 1 开始)构造请求，直到某页返回不足 page_size 条
+    ///
+    /// 每页按当日缓存，任一页失败时返回错误，避免结果被截断；无数据时按 col_schema 返回空表；
+    /// 某页与上一页内容相同(如接口忽略页码)时视为最后一页，超过 MAX_PAGES 页时返回错误
+    ///
+    pub async fn exec_by_pages<F>(
+        format: &F,
+        page_size: usize,
+        request_page: impl Fn(usize) -> Request,
+    ) -> Result<DataFrame, anyhow::Error>
+    where
+        F: DataResultFormat + Clone,
+    {
+        let mut data_frame = format
+            .col_schema()
+            .as_ref()
+            .map(DataFrame::from)
+            .unwrap_or_else(DataFrame::empty);
+        let mut previous: Option<DataFrame> = None;
+
+        for page in 1..=HttpClient::MAX_PAGES {
+            let request = request_page(page);
+            let url = request.url().to_string();
+            let result = HttpClient::exec_by_cache(request, format.clone()).await?;
+
+            let Some(df) = result.data.filter(|df| df.height() > 0) else {
+                return Ok(data_frame);
+            };
+            if previous
+                .as_ref()
+                .is_some_and(|p| p.frame_equal_missing(&df))
+            {
+                tracing::warn!("分页结果与上一页相同，停止翻页: {}", url);
+                return Ok(data_frame);
+            }
+
+            if data_frame.width() == 0 {
+                data_frame = df.clone();
+            } else {
+                data_frame.vstack_mut(&df)?;
+            }
+            if df.height() < page_size {
+                return Ok(data_frame);
+            }
+            previous = Some(df);
+        }
+
+        anyhow::bail!("分页超过 {} 页，停止请求", HttpClient::MAX_PAGES)
+    }
+
+    ///
+    /// 请求数据并格式化，不使用缓存，请求失败、响应状态异常、解析或格式化失败时返回错误
+    ///
+    pub async fn exec_and_format(
+        request: Request,
+        format: impl DataResultFormat,
+    ) -> Result<DataResult<DataFrame>, anyhow::Error> {
+        tracing::debug!("request url: {:?}", request);
+
+        let url = request.url().to_string();
+        let http_client = reqwest::Client::new();
+        let body = http_client
+            .execute(request)
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        let data_frame = format.to_dataframe(Some(body))?;
+        let result = format.format(data_frame.data);
+        if result.data.is_none() {
+            anyhow::bail!("数据格式化失败: {}", url);
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// 请求id，请求url的md5
+    ///
+    pub fn request_id(request: &Request) -> String {
+        let url = request.url();
+        let digest = md5::compute(url.as_str().as_bytes());
+
+        tracing::debug!("digest:{:?}, url: {}", digest, url);
+
+        format!("{:?}", digest)
+    }
+
     /// 将服务器返回的 content-type 解析成 Mime 类型
     fn _get_content_type(resp: &Response) -> Option<Mime> {
         resp.headers()
@@ -106,6 +288,61 @@ impl DateUtils {
         let now = Local::now();
         now.format("%Y-%m-%d").to_string()
     }
+
+    ///
+    /// 20010827、2001-08-27、2001-08-27 00:00:00 统一为 2001-08-27，仅有年月(201501)时取当月首日
+    ///
+    pub fn normalize_ymd(s: &str) -> Option<String> {
+        let digits: String = s.chars().filter(char::is_ascii_digit).collect();
+        match digits.len() {
+            6 => Some(format!("{}-{}-01", &digits[..4], &digits[4..6])),
+            n if n >= 8 => Some(format!(
+                "{}-{}-{}",
+                &digits[..4],
+                &digits[4..6],
+                &digits[6..8]
+            )),
+            _ => None,
+        }
+    }
+
+    ///
+    /// 已定稿的最后一天，此前(含)的数据不再变化，可永久缓存
+    ///
+    /// 收盘即定稿的数据 lag_days 为 1，即今日之前；次日才披露或披露后可能修订的数据按需放宽
+    ///
+    pub fn settled_until(lag_days: i64) -> NaiveDate {
+        Local::now().date_naive() - Duration::days(lag_days)
+    }
+
+    ///
+    /// 按自然月拆分日期区间，返回 (起始日, 截止日, 是否永久缓存)
+    ///
+    /// 整月均已定稿(月末不晚于 settled)的月份按整月查询并永久缓存，缓存 key 不随查询日期变化，
+    /// 其余部分合并为一段按当日缓存
+    ///
+    pub fn cache_periods(
+        start: NaiveDate,
+        end: NaiveDate,
+        settled: NaiveDate,
+    ) -> Vec<(NaiveDate, NaiveDate, bool)> {
+        let mut periods = vec![];
+        let mut month = start.with_day(1).unwrap();
+        while month <= end {
+            let next_month = month + Months::new(1);
+            let month_end = next_month - Duration::days(1);
+            if month_end > settled {
+                break;
+            }
+            periods.push((month, month_end, true));
+            month = next_month;
+        }
+        if month <= end {
+            periods.push((start.max(month), end, false));
+        }
+
+        periods
+    }
 }
 
 pub struct IoUtils;
@@ -125,3 +362,52 @@ impl IoUtils {
         }
     }
 }
+
+pub struct JsonUtils;
+
+impl JsonUtils {
+    ///
+    /// 去掉 JSONP 回调包装，jQuery123({...}); -> {...}
+    ///
+    pub fn unwrap_jsonp(body: &str) -> &str {
+        let body = body.trim();
+        match (body.find('('), body.rfind(')')) {
+            (Some(start), Some(end)) if start < end && !body.starts_with(['{', '[']) => {
+                &body[start + 1..end]
+            }
+            _ => body,
+        }
+    }
+
+    ///
+    /// 数值或数值字符串转为 f64，"1,256.5" / 1256.5 -> 1256.5
+    ///
+    pub fn number(value: Option<&Value>) -> Option<f64> {
+        match value? {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.replace(',', "").parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+pub struct HtmlUtils;
+
+impl HtmlUtils {
+    ///
+    /// 去掉 html 标签及空白，<a ...><u>000003</u></a> -> 000003，<td>&nbsp;</td> -> ""
+    ///
+    pub fn strip_tags(s: &str) -> String {
+        let mut text = String::new();
+        let mut in_tag = false;
+        for c in s.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                c if !in_tag => text.push(c),
+                _ => {}
+            }
+        }
+        text.replace("&nbsp;", "").trim().to_string()
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod billboard_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{DataFrame, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::billboard::{
+            Billboard, EastmoneyBillboardDataSource, EastmoneyBlockTradeDataSource,
+        },
+        DataResultFormat, HistoryData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let start = NaiveDate::from_ymd_opt(2023, 10, 30).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+        let in_range = |df: &DataFrame| -> anyhow::Result<bool> {
+            let dates = df.column("日期")?.utf8()?.clone();
+            let in_range = dates
+                .into_iter()
+                .all(|d| d.is_some_and(|d| ("2023-10-30"..="2023-10-31").contains(&d)));
+            Ok(in_range)
+        };
+
+        let data_source = EastmoneyBillboardDataSource {
+            billboard: Billboard::Stocks,
+        };
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily("", "", start, end)
+            .await?
+            .data
+            .unwrap();
+        tracing::debug!("billboard is: {:?}", df);
+
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0);
+        assert!(in_range(&df)?);
+
+        let data_source = EastmoneyBlockTradeDataSource {};
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily("", "", start, end)
+            .await?
+            .data
+            .unwrap();
+
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0);
+        assert!(in_range(&df)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"SECURITY_CODE":"600666","SECURITY_NAME_ABBR":"ST瑞德","TRADE_DATE":"2023-10-31 00:00:00","EXPLAIN":"2家机构买入","CLOSE_PRICE":2.18,"CHANGE_RATE":5.31,"BILLBOARD_NET_AMT":12345678.5,"BILLBOARD_BUY_AMT":22345678.5,"BILLBOARD_SELL_AMT":10000000,"BILLBOARD_DEAL_AMT":32345678.5,"ACCUM_AMOUNT":150000000,"DEAL_NET_RATIO":8.23,"DEAL_AMOUNT_RATIO":21.56,"TURNOVERRATE":3.4,"FREE_MARKET_CAP":4300000000,"EXPLANATION":"日涨幅偏离值达到7%的前5只证券"}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyBillboardDataSource {
+            billboard: Billboard::Stocks,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 17));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-31"));
+        assert_eq!(df.column("龙虎榜卖出额")?.f64()?.get(0), Some(10000000.0));
+        assert_eq!(
+            df.column("上榜原因")?.utf8()?.get(0),
+            Some("日涨幅偏离值达到7%的前5只证券")
+        );
+
+        let body = r#"{"result":{"pages":1,"data":[{"SECURITY_CODE":"600666","SECURITY_NAME_ABBR":"ST瑞德","TRADE_DATE":"2023-10-31 00:00:00","OPERATEDEPT_CODE":"10656871","OPERATEDEPT_NAME":"机构专用","BUY":5234567.0,"TOTAL_BUYRIO":3.49,"SELL":null,"TOTAL_SELLRIO":null,"NET":5234567.0,"EXPLANATION":"日涨幅偏离值达到7%的前5只证券"}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyBillboardDataSource {
+            billboard: Billboard::BuySeats,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 12));
+        assert_eq!(df.column("营业部名称")?.utf8()?.get(0), Some("机构专用"));
+        assert_eq!(df.column("买入金额")?.f64()?.get(0), Some(5234567.0));
+        assert_eq!(df.column("卖出金额")?.f64()?.get(0), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn block_trade_format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"SECURITY_CODE":"600519","SECURITY_NAME_ABBR":"贵州茅台","TRADE_DATE":"2023-10-31 00:00:00","CHANGE_RATE":0.65,"CLOSE_PRICE":1720.0,"DEAL_PRICE":1548.0,"PREMIUM_RATIO":-10.0,"DEAL_VOLUME":1000,"DEAL_AMT":1548000.0,"TURNOVER_RATE":0.0001,"BUYER_NAME":"机构专用","SELLER_NAME":"中信证券股份有限公司北京总部证券营业部"}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyBlockTradeDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 13));
+        assert_eq!(df.column("成交价")?.f64()?.get(0), Some(1548.0));
+        assert_eq!(df.column("折溢率")?.f64()?.get(0), Some(-10.0));
+        assert_eq!(df.column("成交量")?.f64()?.get(0), Some(1000.0));
+        assert_eq!(df.column("买方营业部")?.utf8()?.get(0), Some("机构专用"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod board_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{DataType, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::board::{
+            BoardType, EastmoneyBoardConsDataSource, EastmoneyBoardSpotDataSource,
+            EastmoneyStockBoardDataSource,
+        },
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        for board_type in [BoardType::Industry, BoardType::Concept] {
+            let data_source = EastmoneyBoardSpotDataSource { board_type };
+            let df = data_source.real_time_data().await?.data.unwrap();
+            tracing::debug!("board spot is: {:?}", df);
+
+            assert_eq!(df.schema(), data_source.col_schema().unwrap());
+            assert!(df.height() > 0);
+            let codes = df.column("板块代码")?.utf8()?.clone();
+            assert!(codes
+                .into_iter()
+                .all(|c| c.is_some_and(|c| c.starts_with("BK"))));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyBoardSpotDataSource {
+            board_type: BoardType::Industry,
+        };
+        let df = data_source
+            .history_daily(
+                "90",
+                "BK0475",
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        // 上半年约 116 个交易日
+        assert!(df.height() > 100);
+        assert_eq!(df.column("收盘")?.dtype(), &DataType::Float64);
+        let dates = df.column("日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-01-01"..="2023-06-30").contains(&d))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn board_cons_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyBoardConsDataSource {
+            board: "BK0475".to_string(),
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert!(df.height() > 0);
+        let boards = df.column("板块代码")?.utf8()?.clone();
+        assert!(boards.into_iter().all(|b| b == Some("BK0475")));
+
+        let data_source = EastmoneyStockBoardDataSource {
+            symbol: "600519".to_string(),
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert!(df.height() > 0);
+        let symbols = df.column("symbol")?.utf8()?.clone();
+        assert!(symbols.into_iter().all(|s| s == Some("600519")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = "{\"rc\":0,\"data\":{\"total\":2,\"diff\":[{\"f2\":1520.3,\"f3\":1.25,\"f4\":18.8,\"f8\":0.5,\"f12\":\"BK0475\",\"f14\":\"银行\",\"f20\":9000000000000,\"f104\":30,\"f105\":12,\"f128\":\"招商银行\",\"f136\":3.1,\"f140\":\"600036\"},{\"f2\":\"-\",\"f3\":\"-\",\"f4\":\"-\",\"f8\":\"-\",\"f12\":\"BK1036\",\"f14\":\"半导体\",\"f20\":\"-\",\"f104\":0,\"f105\":0,\"f128\":\"-\",\"f136\":\"-\",\"f140\":\"-\"}]}}";
+        let data_source = EastmoneyBoardSpotDataSource {
+            board_type: BoardType::Industry,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 13));
+        assert_eq!(df.column("板块代码")?.utf8()?.get(0), Some("BK0475"));
+        assert_eq!(df.column("symbol")?.utf8()?.get(1), Some("BK1036"));
+        assert_eq!(df.column("涨跌幅")?.f64()?.get(0), Some(1.25));
+        assert_eq!(df.column("涨跌幅")?.f64()?.get(1), None);
+        assert_eq!(df.column("上涨家数")?.i64()?.get(0), Some(30));
+
+        Ok(())
+    }
+}
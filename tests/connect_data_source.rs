@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod connect_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::connect::{
+            ConnectChannel, ConnectDirection, EastmoneyConnectFlowDataSource,
+            EastmoneyConnectHoldingDataSource, EastmoneyConnectMinuteDataSource,
+        },
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn flow_history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyConnectFlowDataSource {
+            channel: ConnectChannel::Southbound,
+        };
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily(
+                "",
+                "",
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+        tracing::debug!("southbound flow is: {:?}", df);
+
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 100);
+        let dates = df.column("日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-01-01"..="2023-06-30").contains(&d))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn minute_real_time_data_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyConnectMinuteDataSource {
+            direction: ConnectDirection::Northbound,
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("northbound minute flow is: {:?}", df);
+
+        // 开盘前可能为空，有数据时时间为 HH:MM
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        let times = df.column("时间")?.utf8()?.clone();
+        assert!(times
+            .into_iter()
+            .all(|t| t.is_some_and(|t| t.len() == 5 && t.contains(':'))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn holding_history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyConnectHoldingDataSource {};
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily(
+                "",
+                "600519",
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 3, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c == Some("600519")));
+        let dates = df.column("持股日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-01-01"..="2023-03-31").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn flow_format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"MUTUAL_TYPE":"006","TRADE_DATE":"2023-06-30 00:00:00","FUND_INFLOW":2563.12,"NET_DEAL_AMT":2563.12,"ACCUM_DEAL_AMT":2485627.3,"BUY_AMT":24563.5,"SELL_AMT":22000.38,"LEAD_STOCKS_CODE":"00700","LEAD_STOCKS_NAME":"腾讯控股","LS_CHANGE_RATE":1.82,"INDEX_CLOSE_PRICE":18916.43,"INDEX_CHANGE_RATE":0.14,"QUOTA_BALANCE":39436.88}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyConnectFlowDataSource {
+            channel: ConnectChannel::Southbound,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 11));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-06-30"));
+        assert_eq!(df.column("成交净买额")?.f64()?.get(0), Some(2563.12));
+        assert_eq!(df.column("领涨股")?.utf8()?.get(0), Some("腾讯控股"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn minute_format_works() -> anyhow::Result<()> {
+        let body = r#"{"rc":0,"data":{"s2nDate":"06-30","n2sDate":"06-30","s2n":["9:30,1234.56,-234.5,1000.06","9:31,2234.56,-134.5,2100.06","9:32,-,-,-"],"n2s":["9:30,100.0,200.0,300.0"]}}"#;
+        let data_source = EastmoneyConnectMinuteDataSource {
+            direction: ConnectDirection::Northbound,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (3, 5));
+        assert!(df
+            .column("日期")?
+            .utf8()?
+            .get(0)
+            .unwrap()
+            .ends_with("-06-30"));
+        assert_eq!(df.column("时间")?.utf8()?.get(1), Some("9:31"));
+        assert_eq!(df.column("深市")?.f64()?.get(0), Some(-234.5));
+        assert_eq!(df.column("合计")?.f64()?.get(2), None);
+
+        let data_source = EastmoneyConnectMinuteDataSource {
+            direction: ConnectDirection::Southbound,
+        };
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+        assert_eq!(df.shape(), (1, 5));
+        assert_eq!(df.column("合计")?.f64()?.get(0), Some(300.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn holding_format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"SECUCODE":"600519.SH","TRADE_DATE":"2023-03-31 00:00:00","SECURITY_CODE":"600519","SECURITY_NAME":"贵州茅台","HOLD_SHARES":89461234,"HOLD_MARKET_CAP":160384000000.5,"FREE_SHARES_RATIO":7.12,"TOTAL_SHARES_RATIO":7.12,"CLOSE_PRICE":1800.0,"CHANGE_RATE":0.52}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyConnectHoldingDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 10));
+        assert_eq!(df.column("持股日期")?.utf8()?.get(0), Some("2023-03-31"));
+        assert_eq!(df.column("持股数量")?.f64()?.get(0), Some(89461234.0));
+        assert_eq!(df.column("symbol")?.utf8()?.get(0), Some("600519"));
+
+        Ok(())
+    }
+}
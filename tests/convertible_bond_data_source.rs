@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod convertible_bond_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{DataType, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::bond::convertible::{
+            EastmoneyConvertibleBondInfoDataSource, EastmoneyConvertibleBondSpotDataSource,
+        },
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyConvertibleBondSpotDataSource {};
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("convertible bond spot is: {:?}", df);
+
+        // 比价表各列之后依次为 信用评级,到期日期,剩余年限
+        assert!(df.height() > 0);
+        let names = df.get_column_names();
+        assert_eq!(names.len(), 25);
+        assert_eq!(names[22..], ["信用评级", "到期日期", "剩余年限"]);
+        assert_eq!(df.column("转股溢价率")?.dtype(), &DataType::Float64);
+        assert_eq!(df.column("剩余年限")?.dtype(), &DataType::Float64);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c.is_some_and(|c| c.len() == 6)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyConvertibleBondSpotDataSource {};
+        let df = data_source
+            .history_daily(
+                "sh",
+                "113050",
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        assert!(df.height() > 100);
+        assert_eq!(df.column("收盘")?.dtype(), &DataType::Float64);
+        let dates = df.column("日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-01-01"..="2023-06-30").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = "{\"rc\":0,\"data\":{\"total\":1,\"diff\":[{\"f2\":125.5,\"f3\":0.8,\"f12\":\"113050\",\"f14\":\"南银转债\",\"f26\":20210623,\"f227\":105.2,\"f229\":8.21,\"f230\":1.1,\"f232\":\"601009\",\"f234\":\"南京银行\",\"f235\":9.92,\"f236\":82.76,\"f237\":51.64,\"f238\":19.3,\"f239\":\"-\",\"f240\":12.9,\"f241\":\"-\",\"f242\":20211230,\"f243\":20210615}]}}";
+        let data_source = EastmoneyConvertibleBondSpotDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 22));
+        assert_eq!(df.column("正股代码")?.utf8()?.get(0), Some("601009"));
+        assert_eq!(df.column("转股溢价率")?.f64()?.get(0), Some(51.64));
+        assert_eq!(df.column("回售触发价")?.f64()?.get(0), None);
+
+        let body = "{\"result\":{\"pages\":1,\"data\":[{\"SECURITY_CODE\":\"113050\",\"SECURITY_NAME_ABBR\":\"南银转债\",\"CONVERT_STOCK_CODE\":\"601009\",\"RATING\":\"AAA\",\"ACTUAL_ISSUE_SCALE\":200,\"BOND_EXPIRE\":\"6\",\"INITIAL_TRANSFER_PRICE\":10.1,\"PUBLIC_START_DATE\":\"2021-06-15 00:00:00\",\"LISTING_DATE\":\"2021-07-08 00:00:00\",\"EXPIRE_DATE\":\"2027-06-14 00:00:00\"}]},\"success\":true}";
+        let data_source = EastmoneyConvertibleBondInfoDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 10));
+        assert_eq!(df.column("信用评级")?.utf8()?.get(0), Some("AAA"));
+        assert_eq!(df.column("发行年限")?.f64()?.get(0), Some(6.0));
+        assert_eq!(df.column("到期日期")?.utf8()?.get(0), Some("2027-06-14"));
+
+        Ok(())
+    }
+}
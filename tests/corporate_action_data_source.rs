@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod corporate_action_data_source_works {
+    use polars::{
+        df,
+        prelude::{NamedFrom, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::corporate_action::{CorporateAction, EastmoneyCorporateActionDataSource},
+        DataResultFormat, HttpSource, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        for action in [
+            CorporateAction::Dividend,
+            CorporateAction::RightsIssue,
+            CorporateAction::ShareChange,
+        ] {
+            let data_source = EastmoneyCorporateActionDataSource {
+                action,
+                symbol: "600519".to_string(),
+            };
+            let df = data_source.real_time_data().await?.data.unwrap();
+            tracing::debug!("corporate action is: {:?}", df);
+
+            // 贵州茅台上市以来未配股，配股结果为空表
+            assert_eq!(df.schema(), data_source.col_schema().unwrap());
+            let codes = df.column("代码")?.utf8()?.clone();
+            assert!(codes.into_iter().all(|c| c == Some("600519")));
+            if data_source.action != CorporateAction::RightsIssue {
+                assert!(df.height() > 0);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn request_works() {
+        let data_source = EastmoneyCorporateActionDataSource {
+            action: CorporateAction::ShareChange,
+            symbol: "sz000001".to_string(),
+        };
+        let url = data_source.request().url().to_string();
+        assert!(url.contains("RPT_F10_EH_EQUITY"));
+        assert!(url.contains("000001.SZ"));
+
+        let data_source = EastmoneyCorporateActionDataSource {
+            action: CorporateAction::Dividend,
+            symbol: "sh600519".to_string(),
+        };
+        let url = data_source.request().url().to_string();
+        assert!(url.contains("%22600519%22"));
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"SECURITY_CODE":"600519","SECURITY_NAME_ABBR":"贵州茅台","REPORT_DATE":"2022-12-31 00:00:00","PLAN_NOTICE_DATE":"2023-04-01 00:00:00","NOTICE_DATE":"2023-06-26 00:00:00","EQUITY_RECORD_DATE":"2023-06-29 00:00:00","EX_DIVIDEND_DATE":"2023-06-30 00:00:00","ASSIGN_PROGRESS":"实施分配","IMPL_PLAN_PROFILE":"10派259.11元(含税)","PRETAX_BONUS_RMB":259.11,"BONUS_IT_RATIO":null,"BONUS_RATIO":null,"IT_RATIO":null,"DIVIDENT_RATIO":0.0141,"TOTAL_SHARES":1256197800}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyCorporateActionDataSource {
+            action: CorporateAction::Dividend,
+            symbol: "600519".to_string(),
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 16));
+        assert_eq!(df.column("除权除息日")?.utf8()?.get(0), Some("2023-06-30"));
+        assert_eq!(df.column("股权登记日")?.utf8()?.get(0), Some("2023-06-29"));
+        assert_eq!(df.column("现金分红")?.f64()?.get(0), Some(259.11));
+        assert_eq!(df.column("送转总比例")?.f64()?.get(0), None);
+        assert_eq!(df.column("总股本")?.f64()?.get(0), Some(1256197800.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn adjust_factors_works() -> anyhow::Result<()> {
+        let dividends = df!(
+            "除权除息日" => ["2023-06-30", "2022-07-01", "2021-06-25"],
+            "现金分红" => [Some(10.0), Some(5.0), None],
+            "送转总比例" => [None, Some(10.0), None]
+        )?;
+        let rights = df!(
+            "除权日" => [Some("2023-06-30"), Some("2022-03-01"), None],
+            "配股比例" => [Some(3.0), Some(2.0), Some(3.0)],
+            "配股价" => [Some(6.0), Some(5.0), Some(4.0)]
+        )?;
+        let closes = df!(
+            "日期" => ["2022-02-28", "2022-06-30", "2022-07-01", "2023-06-29", "2023-06-30"],
+            "收盘" => [11.0, 20.5, 10.0, 11.6, 10.0]
+        )?;
+
+        let df = EastmoneyCorporateActionDataSource::adjust_factors(&dividends, &rights, &closes)?;
+
+        // 无收盘价的除权日、无分红送转及无除权日的配股被忽略，按除权日升序
+        assert_eq!(df.height(), 3);
+        assert_eq!(df.column("除权除息日")?.utf8()?.get(0), Some("2022-03-01"));
+        // 配股: (11 + 5 * 0.2) / (1 + 0.2) = 10
+        let reference = df.column("除权参考价")?.f64()?.get(0).unwrap();
+        assert!((reference - 10.0).abs() < 1e-9);
+        // 送转: (20.5 - 0.5) / (1 + 1) = 10
+        assert_eq!(df.column("除权除息日")?.utf8()?.get(1), Some("2022-07-01"));
+        assert_eq!(df.column("除权参考价")?.f64()?.get(1), Some(10.0));
+        assert_eq!(df.column("复权因子")?.f64()?.get(1), Some(2.05));
+        // 同一除权日的分红与配股合并: (11.6 - 1 + 6 * 0.3) / (1 + 0.3) = 9.538...
+        let reference = df.column("除权参考价")?.f64()?.get(2).unwrap();
+        assert!((reference - 12.4 / 1.3).abs() < 1e-9);
+        let cumulative = df.column("累计复权因子")?.f64()?.get(2).unwrap();
+        assert!((cumulative - 1.1 * 2.05 * (11.6 / reference)).abs() < 1e-9);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod crypto_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        crypto::{
+            binance::BinanceExchange,
+            exchange::{CryptoKlineDataSource, CryptoTickerDataSource, KlineInterval},
+        },
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+    use std::sync::Once;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    const TICKERS: &str = r#"[{"symbol":"BTCUSDT","priceChange":"-120.50","priceChangePercent":"-0.349","lastPrice":"34380.00","openPrice":"34500.50","highPrice":"34800.00","lowPrice":"34100.00","volume":"25000.123","quoteVolume":"861000000.5"},{"symbol":"ETHUSDT","priceChange":"10.00","priceChangePercent":"0.556","lastPrice":"1810.00","openPrice":"1800.00","highPrice":"1830.00","lowPrice":"1790.00","volume":"300000","quoteVolume":"543000000"}]"#;
+
+    // 2023-01-01 ~ 2023-01-03 UTC 日K线
+    const KLINES: &str = r#"[[1672531200000,"16541.77","16628.00","16499.01","16616.75","96925.41",1672617599999,"1607919235.88",1567000,"48605.99","806084738.52","0"],[1672617600000,"16617.17","16799.23","16548.70","16672.87","121888.57",1672703999999,"2028237062.33",1895000,"61137.83","1017329386.29","0"],[1672704000000,"16672.78","16778.40","16605.28","16675.18","159541.53",1672790399999,"2663327066.07",2209000,"79722.93","1330977924.99","0"]]"#;
+
+    static CACHE_HOME: Once = Once::new();
+
+    ///
+    /// 模拟接口端口随机，缓存 key 每次运行都不同，缓存目录指向 target 下的独立目录并在首次使用时清空
+    ///
+    fn isolate_cache() {
+        CACHE_HOME.call_once(|| {
+            let home = format!("{}/crypto_data_source", env!("CARGO_TARGET_TMPDIR"));
+            let _ = std::fs::remove_dir_all(&home);
+            std::fs::create_dir_all(&home).unwrap();
+            std::env::set_var("CACHE_TEMP_HOME", home);
+        });
+    }
+
+    ///
+    /// 本地模拟币安接口，按请求路径返回固定响应
+    ///
+    async fn mock_server() -> anyhow::Result<String> {
+        isolate_cache();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 4096];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let body = if request.starts_with("GET /api/v3/ticker/24hr") {
+                    TICKERS
+                } else if request.starts_with("GET /api/v3/klines") {
+                    KLINES
+                } else {
+                    "[]"
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        Ok(format!("http://{}", addr))
+    }
+
+    #[tokio::test]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = CryptoTickerDataSource {
+            exchange: BinanceExchange {
+                base_url: mock_server().await?,
+            },
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("crypto tickers is: {:?}", df);
+
+        assert_eq!(df.shape(), (2, 9));
+        assert_eq!(df.column("代码")?.utf8()?.get(0), Some("BTCUSDT"));
+        assert_eq!(df.column("最新价")?.f64()?.get(0), Some(34380.0));
+        assert_eq!(df.column("涨跌幅")?.f64()?.get(1), Some(0.556));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = CryptoKlineDataSource {
+            exchange: BinanceExchange {
+                base_url: mock_server().await?,
+            },
+            interval: KlineInterval::Day1,
+        };
+        let df = data_source
+            .history_daily(
+                "",
+                "BTCUSDT",
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 3).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        assert_eq!(df.shape(), (3, 8));
+        assert_eq!(
+            df.column("时间")?.utf8()?.get(0),
+            Some("2023-01-01 00:00:00")
+        );
+        assert_eq!(df.column("收盘")?.f64()?.get(2), Some(16675.18));
+        assert_eq!(df.column("开盘时间戳")?.i64()?.get(1), Some(1672617600000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn kline_format_works() -> anyhow::Result<()> {
+        let data_source = CryptoKlineDataSource {
+            exchange: BinanceExchange::default(),
+            interval: KlineInterval::Day1,
+        };
+
+        let data_result = data_source.to_dataframe(Some(KLINES.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (3, 8));
+        assert_eq!(
+            df.column("时间")?.utf8()?.get(2),
+            Some("2023-01-03 00:00:00")
+        );
+        assert_eq!(df.column("最高")?.f64()?.get(1), Some(16799.23));
+        assert_eq!(df.column("成交额")?.f64()?.get(0), Some(1607919235.88));
+
+        // 无数据时返回带列名的空表
+        let data_result = data_source.to_dataframe(Some("[]".to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+        assert_eq!(df.shape(), (0, 8));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod financial_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::financial::{EastmoneyFinancialDataSource, FinancialStatement},
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyFinancialDataSource {
+            statement: FinancialStatement::Indicator,
+            symbol: Some("600519".to_string()),
+            report_date: Some(NaiveDate::from_ymd_opt(2023, 9, 30).unwrap()),
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("financial indicator is: {:?}", df);
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert_eq!(df.height(), 1);
+        assert_eq!(df.column("代码")?.utf8()?.get(0), Some("600519"));
+        assert_eq!(df.column("报告期")?.utf8()?.get(0), Some("2023-09-30"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyFinancialDataSource {
+            statement: FinancialStatement::BalanceSheet,
+            symbol: None,
+            report_date: None,
+        };
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily(
+                "",
+                "600519",
+                NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c == Some("600519")));
+        let dates = df.column("报告期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2022-01-01"..="2023-12-31").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"SECURITY_CODE":"600519","SECURITY_NAME_ABBR":"贵州茅台","REPORTDATE":"2023-09-30 00:00:00","NOTICE_DATE":"2023-10-21 00:00:00","UPDATE_DATE":"2023-10-21 00:00:00","BASIC_EPS":41.76,"DEDUCT_BASIC_EPS":41.79,"TOTAL_OPERATE_INCOME":105177000000.0,"YSTZ":17.26,"PARENT_NETPROFIT":52456000000.0,"SJLTZ":19.09,"BPS":181.37,"WEIGHTAVG_ROE":24.44,"MGJYXJJE":37.33,"XSMLL":91.8},{"SECURITY_CODE":"600519","SECURITY_NAME_ABBR":"贵州茅台","REPORTDATE":"2023-06-30 00:00:00","NOTICE_DATE":"2023-08-03 00:00:00","UPDATE_DATE":"2023-08-03 00:00:00","BASIC_EPS":28.64,"DEDUCT_BASIC_EPS":28.66,"TOTAL_OPERATE_INCOME":70916000000.0,"YSTZ":18.76,"PARENT_NETPROFIT":35980000000.0,"SJLTZ":20.76,"BPS":168.22,"WEIGHTAVG_ROE":16.8,"MGJYXJJE":18.98,"XSMLL":91.86}],"count":2},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyFinancialDataSource {
+            statement: FinancialStatement::Indicator,
+            symbol: Some("600519".to_string()),
+            report_date: None,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 16));
+        assert_eq!(df.column("报告期")?.utf8()?.get(0), Some("2023-09-30"));
+        assert_eq!(df.column("公告日期")?.utf8()?.get(0), Some("2023-10-21"));
+        assert_eq!(df.column("净资产收益率")?.f64()?.get(0), Some(24.44));
+        assert_eq!(df.column("销售毛利率")?.f64()?.get(1), Some(91.86));
+        assert_eq!(df.column("每股收益")?.f64()?.get(1), Some(28.64));
+
+        // 2023-10-20 三季报尚未公告，时点数据为半年报
+        let pit = EastmoneyFinancialDataSource::point_in_time(
+            &df,
+            NaiveDate::from_ymd_opt(2023, 10, 20).unwrap(),
+        )?;
+        assert_eq!(pit.height(), 1);
+        assert_eq!(pit.column("报告期")?.utf8()?.get(0), Some("2023-06-30"));
+
+        let pit = EastmoneyFinancialDataSource::point_in_time(
+            &df,
+            NaiveDate::from_ymd_opt(2023, 10, 21).unwrap(),
+        )?;
+        assert_eq!(pit.column("报告期")?.utf8()?.get(0), Some("2023-09-30"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn statement_format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"SECURITY_CODE":"000001","SECURITY_NAME_ABBR":"平安银行","REPORT_DATE":"2023-09-30 00:00:00","NOTICE_DATE":"2023-10-25 00:00:00","NETCASH_OPERATE":-12345.6,"NETCASH_OPERATE_RATIO":null,"SALES_SERVICES":null,"NETCASH_INVEST":2345.6,"NETCASH_FINANCE":-345.6,"CCE_ADD":100.0}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyFinancialDataSource {
+            statement: FinancialStatement::CashFlow,
+            symbol: None,
+            report_date: Some(NaiveDate::from_ymd_opt(2023, 9, 30).unwrap()),
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 11));
+        assert_eq!(df.column("报告期")?.utf8()?.get(0), Some("2023-09-30"));
+        assert_eq!(df.column("经营性现金流净额")?.f64()?.get(0), Some(-12345.6));
+        assert_eq!(df.column("销售商品收到的现金")?.f64()?.get(0), None);
+
+        Ok(())
+    }
+}
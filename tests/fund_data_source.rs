@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod fund_data_source_works {
+    use polars::prelude::{TakeRandom, TakeRandomUtf8};
+    use qshare::{
+        sina::fund::etf::{EastmoneyFundSpotDataSource, FundType},
+        DataResultFormat, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        for fund_type in [FundType::Etf, FundType::Lof] {
+            let data_source = EastmoneyFundSpotDataSource { fund_type };
+            let df = data_source.real_time_data().await?.data.unwrap();
+            tracing::debug!("fund spot is: {:?}", df);
+
+            assert_eq!(df.schema(), data_source.col_schema().unwrap());
+            assert!(df.height() > 0);
+            // 场内基金代码为6位数字，上交所以5开头，深交所以1开头
+            let codes = df.column("代码")?.utf8()?.clone();
+            assert!(codes
+                .into_iter()
+                .all(|c| c.is_some_and(|c| c.len() == 6 && c.starts_with(['1', '5']))));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = "{\"rc\":0,\"data\":{\"total\":1,\"diff\":[{\"f2\":3.912,\"f3\":0.46,\"f4\":0.018,\"f5\":5213000,\"f6\":2036000000.0,\"f12\":\"510300\",\"f14\":\"沪深300ETF\",\"f38\":24500000000.0,\"f297\":20231018,\"f402\":-0.05,\"f441\":3.914}]}}";
+        let data_source = EastmoneyFundSpotDataSource {
+            fund_type: FundType::Etf,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 20));
+        assert_eq!(df.column("代码")?.utf8()?.get(0), Some("510300"));
+        assert_eq!(df.column("IOPV实时估值")?.f64()?.get(0), Some(3.914));
+        assert_eq!(df.column("折价率")?.f64()?.get(0), Some(-0.05));
+        assert_eq!(df.column("数据日期")?.utf8()?.get(0), Some("20231018"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod fund_nav_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{DataType, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::fund::nav::EastmoneyFundNavDataSource, utils::JsonUtils, DataResultFormat,
+        HistoryData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyFundNavDataSource {};
+        let df = data_source
+            .history_daily(
+                "",
+                "000001",
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 3, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+        tracing::debug!("fund nav is: {:?}", df);
+
+        // 一季度约57个交易日，超过单页条数，覆盖分页合并
+        assert!(df.height() > 50);
+        assert_eq!(df.get_column_names()[0], "代码");
+        assert_eq!(df.column("单位净值")?.dtype(), &DataType::Float64);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c == Some("000001")));
+        let dates = df.column("净值日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-01-01"..="2023-03-31").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unwrap_jsonp_works() {
+        assert_eq!(
+            JsonUtils::unwrap_jsonp("jQuery123({\"a\":1});"),
+            "{\"a\":1}"
+        );
+        assert_eq!(
+            JsonUtils::unwrap_jsonp("{\"a\":\"(1)\"}"),
+            "{\"a\":\"(1)\"}"
+        );
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = "jQuery18306596328894644803_1571038362181({\"Data\":{\"LSJZList\":[{\"FSRQ\":\"2023-03-31\",\"DWJZ\":\"1.0580\",\"LJJZ\":\"3.7350\",\"SDATE\":null,\"ACTUALSYI\":\"\",\"NAVTYPE\":\"1\",\"JZZZL\":\"0.38\",\"SGZT\":\"开放申购\",\"SHZT\":\"开放赎回\",\"FHFCZ\":\"\",\"FHFCBZ\":\"\",\"DTYPE\":null,\"FHSP\":\"\"},{\"FSRQ\":\"2023-03-30\",\"DWJZ\":\"1.0540\",\"LJJZ\":\"3.7310\",\"SDATE\":null,\"ACTUALSYI\":\"\",\"NAVTYPE\":\"1\",\"JZZZL\":\"\",\"SGZT\":\"开放申购\",\"SHZT\":\"开放赎回\",\"FHFCZ\":\"\",\"FHFCBZ\":\"\",\"DTYPE\":null,\"FHSP\":\"\"}],\"FundType\":\"001\"},\"ErrCode\":0,\"TotalCount\":2,\"PageSize\":20,\"PageIndex\":1})";
+        let data_source = EastmoneyFundNavDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 7));
+        assert_eq!(df.column("净值日期")?.utf8()?.get(0), Some("2023-03-31"));
+        assert_eq!(df.column("单位净值")?.f64()?.get(0), Some(1.058));
+        assert_eq!(df.column("累计净值")?.f64()?.get(1), Some(3.731));
+        assert_eq!(df.column("日增长率")?.f64()?.get(1), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod futures_continuous_works {
+    use polars::{
+        df,
+        prelude::{DataFrame, NamedFrom, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::sina::futures::continuous::{AdjustType, ContinuousContract, RollBy};
+
+    ///
+    /// RB2401 持仓在 01-04 被 RB2405 超过，01-05 切换主力
+    ///
+    fn bars() -> DataFrame {
+        df!(
+            "symbol" => &["RB2401", "RB2405", "RB2401", "RB2405", "RB2401", "RB2405", "RB2401", "RB2405"],
+            "日期" => &["2024-01-02", "2024-01-02", "2024-01-03", "2024-01-03", "2024-01-04", "2024-01-04", "2024-01-05", "2024-01-05"],
+            "开盘" => &[100.0, 110.0, 101.0, 111.0, 102.0, 112.0, 103.0, 113.0],
+            "最高" => &[100.0, 110.0, 101.0, 111.0, 102.0, 112.0, 103.0, 113.0],
+            "最低" => &[100.0, 110.0, 101.0, 111.0, 102.0, 112.0, 103.0, 113.0],
+            "收盘" => &[100.0, 110.0, 101.0, 111.0, 100.0, 120.0, 103.0, 123.0],
+            "成交量" => &[50.0, 10.0, 50.0, 10.0, 10.0, 50.0, 10.0, 50.0],
+            "持仓量" => &[500.0, 100.0, 400.0, 300.0, 200.0, 600.0, 100.0, 700.0]
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn main_contract_works() -> anyhow::Result<()> {
+        let continuous = ContinuousContract {
+            roll_by: RollBy::OpenInterest,
+            adjust: AdjustType::None,
+        }
+        .build(&bars())?;
+
+        let symbols = continuous.data.column("symbol")?.utf8()?.clone();
+        assert_eq!(continuous.data.height(), 4);
+        assert_eq!(symbols.get(2), Some("RB2401"));
+        assert_eq!(symbols.get(3), Some("RB2405"));
+        assert_eq!(continuous.data.column("收盘")?.f64()?.get(3), Some(123.0));
+
+        assert_eq!(continuous.rolls.height(), 1);
+        assert_eq!(
+            continuous.rolls.column("日期")?.utf8()?.get(0),
+            Some("2024-01-05")
+        );
+        assert_eq!(continuous.rolls.column("价差")?.f64()?.get(0), Some(20.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn adjusted_works() -> anyhow::Result<()> {
+        let back = ContinuousContract {
+            roll_by: RollBy::OpenInterest,
+            adjust: AdjustType::BackAdjusted,
+        }
+        .build(&bars())?;
+        let close = back.data.column("收盘")?.f64()?.clone();
+        assert_eq!(close.get(0), Some(120.0));
+        assert_eq!(close.get(2), Some(120.0));
+        assert_eq!(close.get(3), Some(123.0));
+
+        let ratio = ContinuousContract {
+            roll_by: RollBy::OpenInterest,
+            adjust: AdjustType::RatioAdjusted,
+        }
+        .build(&bars())?;
+        let close = ratio.data.column("收盘")?.f64()?.clone();
+        assert_eq!(close.get(0), Some(120.0));
+        assert_eq!(close.get(3), Some(123.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn roll_by_volume_works() -> anyhow::Result<()> {
+        let continuous = ContinuousContract {
+            roll_by: RollBy::Volume,
+            adjust: AdjustType::None,
+        }
+        .build(&bars())?;
+
+        assert_eq!(
+            continuous.rolls.column("新合约")?.utf8()?.get(0),
+            Some("RB2405")
+        );
+        assert_eq!(
+            continuous.rolls.column("日期")?.utf8()?.get(0),
+            Some("2024-01-05")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn zce_decade_roll_works() -> anyhow::Result<()> {
+        // 郑商所合约年份一位，MA001(2020年1月) 按字符串小于 MA912，应按交割年月向远月切换
+        let bars = df!(
+            "symbol" => &["MA912", "MA001", "MA912", "MA001", "MA912", "MA001"],
+            "日期" => &["2019-11-01", "2019-11-01", "2019-11-04", "2019-11-04", "2019-11-05", "2019-11-05"],
+            "开盘" => &[2000.0, 2100.0, 2000.0, 2100.0, 2000.0, 2100.0],
+            "最高" => &[2000.0, 2100.0, 2000.0, 2100.0, 2000.0, 2100.0],
+            "最低" => &[2000.0, 2100.0, 2000.0, 2100.0, 2000.0, 2100.0],
+            "收盘" => &[2000.0, 2100.0, 2010.0, 2110.0, 2020.0, 2120.0],
+            "成交量" => &[50.0, 10.0, 10.0, 50.0, 10.0, 50.0],
+            "持仓量" => &[500.0, 100.0, 100.0, 600.0, 100.0, 700.0]
+        )?;
+
+        let continuous = ContinuousContract {
+            roll_by: RollBy::OpenInterest,
+            adjust: AdjustType::None,
+        }
+        .build(&bars)?;
+
+        let symbols = continuous.data.column("symbol")?.utf8()?.clone();
+        assert_eq!(symbols.get(0), Some("MA912"));
+        assert_eq!(symbols.get(2), Some("MA001"));
+        assert_eq!(continuous.rolls.height(), 1);
+        assert_eq!(
+            continuous.rolls.column("日期")?.utf8()?.get(0),
+            Some("2019-11-05")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_price_skipped_works() -> anyhow::Result<()> {
+        let bars = df!(
+            "symbol" => &["RB2401", "RB2401", "RB2401"],
+            "日期" => &["2024-01-02", "2024-01-03", "2024-01-04"],
+            "开盘" => &[Some(100.0), None, Some(102.0)],
+            "最高" => &[Some(100.0), None, Some(102.0)],
+            "最低" => &[Some(100.0), None, Some(102.0)],
+            "收盘" => &[Some(100.0), None, Some(102.0)],
+            "成交量" => &[Some(50.0), None, Some(50.0)],
+            "持仓量" => &[Some(500.0), None, Some(500.0)]
+        )?;
+
+        let continuous = ContinuousContract {
+            roll_by: RollBy::OpenInterest,
+            adjust: AdjustType::None,
+        }
+        .build(&bars)?;
+
+        // 缺少价格的行被跳过，不以 0 填充
+        assert_eq!(continuous.data.height(), 2);
+        assert_eq!(
+            continuous.data.column("日期")?.utf8()?.get(1),
+            Some("2024-01-04")
+        );
+        assert_eq!(continuous.data.column("最低")?.f64()?.get(1), Some(102.0));
+
+        Ok(())
+    }
+}
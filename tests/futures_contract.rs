@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod futures_contract_works {
+    use polars::export::chrono::NaiveDate;
+    use qshare::{
+        sina::futures::contract::{ContractSpec, Exchange, FuturesContractDataSource},
+        RealTimeData,
+    };
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn contract_spec_works() -> anyhow::Result<()> {
+        let rb = ContractSpec::get("RB").unwrap();
+        assert_eq!(rb.exchange, Exchange::Shfe);
+        assert_eq!(rb.pnl(10.0, 2.0), 200.0);
+        assert_eq!(rb.margin(3600.0, 1.0), 3600.0 * 10.0 * 0.09);
+
+        let specs = ContractSpec::specs()?;
+        assert!(specs.height() > 50);
+
+        Ok(())
+    }
+
+    #[test]
+    fn last_trading_day_works() {
+        assert_eq!(
+            ContractSpec::get("rb").unwrap().last_trading_day(2024, 1),
+            ymd(2024, 1, 15)
+        );
+        assert_eq!(
+            ContractSpec::get("m").unwrap().last_trading_day(2024, 1),
+            ymd(2024, 1, 12)
+        );
+        assert_eq!(
+            ContractSpec::get("IF").unwrap().last_trading_day(2024, 1),
+            ymd(2024, 1, 19)
+        );
+        assert_eq!(
+            ContractSpec::get("sc").unwrap().last_trading_day(2023, 12),
+            ymd(2023, 11, 30)
+        );
+    }
+
+    #[test]
+    fn active_contracts_works() {
+        let symbols = |product: &str| -> Vec<String> {
+            ContractSpec::get(product)
+                .unwrap()
+                .active_contracts(ymd(2023, 10, 18))
+                .into_iter()
+                .map(|(s, _)| s)
+                .collect()
+        };
+
+        assert_eq!(symbols("IF"), vec!["IF2310", "IF2311", "IF2312", "IF2403"]);
+        assert_eq!(symbols("T"), vec!["T2312", "T2403", "T2406"]);
+
+        let ma = symbols("MA");
+        assert_eq!(ma.len(), 12);
+        assert_eq!(ma[0], "MA311");
+        assert_eq!(ma[11], "MA410");
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖新浪期货行情接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = FuturesContractDataSource {
+            product: "rb".to_string(),
+            date: ymd(2023, 10, 18),
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("rb contracts is: {:?}", df);
+
+        assert_eq!(df.schema(), data_source.load_cached_schema().unwrap());
+        assert_eq!(df.height(), 12);
+        let products = df.column("品种")?.utf8()?.clone();
+        assert!(products.into_iter().all(|p| p == Some("rb")));
+        let last_days = df.column("最后交易日")?.utf8()?.clone();
+        assert!(last_days
+            .into_iter()
+            .all(|d| d.is_some_and(|d| d >= "2023-10-18")));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod futures_data_source_works {
+    use polars::prelude::{TakeRandom, TakeRandomUtf8};
+    use qshare::{sina::futures::sina::SinaFuturesSpotDataSource, DataResultFormat, RealTimeData};
+
+    #[tokio::test]
+    #[ignore = "依赖新浪期货行情接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let symbols = vec!["RB0".to_string(), "M2405".to_string(), "SC2312".to_string()];
+        let data_source = SinaFuturesSpotDataSource {
+            symbols: symbols.clone(),
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("futures spot is: {:?}", df);
+
+        // 已到期合约无行情被跳过，连续合约 RB0 始终有行情
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        let returned = df.column("symbol")?.utf8()?.clone();
+        assert!(returned
+            .into_iter()
+            .all(|s| s.is_some_and(|s| symbols.iter().any(|x| x == s))));
+        assert!(df
+            .column("symbol")?
+            .utf8()?
+            .into_iter()
+            .any(|s| s == Some("RB0")));
+        let last = df.column("最新价")?.f64()?.clone();
+        assert!(last.into_iter().all(|p| p.is_some_and(|p| p > 0.0)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = "var hq_str_nf_RB2401=\"螺纹钢2401,145958,3675.000,3697.000,3660.000,3670.000,3681.000,3682.000,3681.000,3680.000,3690.000,1234,567,1867000.000,1234567,沪,螺纹钢,2023-10-18,1,,,,,,,,,3675.500,0.000,0,0.000,0\";\nvar hq_str_nf_XX2401=\"\";\n";
+        let data_source = SinaFuturesSpotDataSource {
+            symbols: vec!["RB2401".to_string(), "XX2401".to_string()],
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 17));
+        assert_eq!(df.column("symbol")?.utf8()?.get(0), Some("RB2401"));
+        assert_eq!(df.column("最新价")?.f64()?.get(0), Some(3681.0));
+        assert_eq!(df.column("结算价")?.f64()?.get(0), Some(3680.0));
+        assert_eq!(df.column("昨结算")?.f64()?.get(0), Some(3690.0));
+        assert_eq!(df.column("持仓量")?.f64()?.get(0), Some(1867000.0));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-18"));
+
+        Ok(())
+    }
+}
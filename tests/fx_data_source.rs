@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod fx_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{DataType, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::{
+            fx::cny::{ChinaMoneyCnyFixingDataSource, EastmoneyCnhDataSource},
+            metal::sge::{SgeBenchmark, SgeBenchmarkDataSource},
+        },
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖外汇交易中心、东方财富及上海金交所接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = ChinaMoneyCnyFixingDataSource {
+            pair: Some("USD/CNY".to_string()),
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("cny fixing is: {:?}", df);
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert_eq!(df.height(), 1);
+        assert_eq!(df.column("货币对")?.utf8()?.get(0), Some("USD/CNY"));
+        assert!(df.column("中间价")?.f64()?.get(0).is_some_and(|p| p > 0.0));
+
+        let data_source = EastmoneyCnhDataSource {};
+        let df = data_source.real_time_data().await?.data.unwrap();
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().any(|c| c == Some("USDCNH")));
+
+        let data_source = SgeBenchmarkDataSource {
+            benchmark: SgeBenchmark::Gold,
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert!(df.height() > 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyCnhDataSource {};
+        let df = data_source
+            .history_daily(
+                "",
+                "USDCNH",
+                NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 10, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        assert!(df.height() > 0);
+        assert_eq!(df.column("收盘")?.dtype(), &DataType::Float64);
+        let dates = df.column("日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-10-01"..="2023-10-31").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cny_fixing_format_works() -> anyhow::Result<()> {
+        let body = r#"{"head":{"rep_code":"200"},"data":{"head":["USD/CNY","EUR/CNY","100JPY/CNY"]},"records":[{"date":"2023-10-31","values":["7.1779","7.5802","4.7765"]},{"date":"2023-10-30","values":["7.1778","7.5838","---"]}]}"#;
+        let data_source = ChinaMoneyCnyFixingDataSource { pair: None };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        // 无报价的 --- 被忽略
+        assert_eq!(df.shape(), (5, 3));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-31"));
+        assert_eq!(df.column("货币对")?.utf8()?.get(1), Some("EUR/CNY"));
+        assert_eq!(df.column("中间价")?.f64()?.get(0), Some(7.1779));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sge_format_works() -> anyhow::Result<()> {
+        // 2023-10-30、2023-10-31 北京时间 00:00
+        let body = r#"{"zp":[[1698595200000,465.25],[1698681600000,468.12]],"wp":[[1698595200000,466.0]]}"#;
+        let data_source = SgeBenchmarkDataSource {
+            benchmark: SgeBenchmark::Gold,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 3));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-31"));
+        assert_eq!(df.column("早盘价")?.f64()?.get(0), Some(468.12));
+        assert_eq!(df.column("午盘价")?.f64()?.get(0), None);
+        assert_eq!(df.column("午盘价")?.f64()?.get(1), Some(466.0));
+
+        Ok(())
+    }
+}
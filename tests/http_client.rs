@@ -1,5 +1,263 @@
 #[cfg(test)]
 mod utils_works {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Once,
+    };
+
+    use polars::{
+        export::chrono::{Duration, Local, NaiveDate},
+        prelude::{DataFrame, DataType, NamedFrom, Schema, Series},
+    };
+    use qshare::{
+        utils::{DateUtils, HttpClient},
+        DataResult, DataResultFormat,
+    };
+    use reqwest::{Method, Request, Url};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
     #[test]
     fn http_client_works() {}
+
+    static CACHE_HOME: Once = Once::new();
+
+    ///
+    /// 模拟接口端口随机，缓存 key 每次运行都不同，缓存目录指向 target 下的独立目录并在首次使用时清空
+    ///
+    fn isolate_cache() {
+        CACHE_HOME.call_once(|| {
+            let home = format!("{}/http_client", env!("CARGO_TARGET_TMPDIR"));
+            let _ = std::fs::remove_dir_all(&home);
+            std::fs::create_dir_all(&home).unwrap();
+            std::env::set_var("CACHE_TEMP_HOME", home);
+        });
+    }
+
+    ///
+    /// 响应为逗号分隔的整数，空响应为无数据
+    ///
+    #[derive(Clone)]
+    struct PageFormat;
+
+    impl DataResultFormat for PageFormat {
+        fn to_dataframe(&self, source: Option<String>) -> anyhow::Result<DataResult<DataFrame>> {
+            let body = source.unwrap_or_default();
+            let values = body
+                .split(',')
+                .filter(|v| !v.is_empty())
+                .map(|v| v.parse::<i64>())
+                .collect::<Result<Vec<_>, _>>()?;
+            let df = DataFrame::new(vec![Series::new("v", values)])?;
+
+            Ok(DataResult::new("".to_string(), df))
+        }
+
+        fn col_alias(&self) -> Option<Vec<(&str, &str)>> {
+            Some(vec![("v", "值")])
+        }
+
+        fn col_schema(&self) -> Option<Schema> {
+            let mut schema = Schema::new();
+            schema.with_column("值".to_string(), DataType::Int64);
+
+            Some(schema)
+        }
+    }
+
+    ///
+    /// 本地模拟分页接口，/full 前两页各2条、第3页1条，/empty 无数据，/fail 第2页返回 500，
+    /// /same 忽略页码始终返回相同的2条
+    ///
+    async fn mock_server(hits: Arc<AtomicUsize>) -> anyhow::Result<String> {
+        isolate_cache();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                hits.fetch_add(1, Ordering::SeqCst);
+                let mut buf = vec![0u8; 4096];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or("");
+                let (status, body) = match path {
+                    "/full?page=1" => ("200 OK", "1,2"),
+                    "/full?page=2" => ("200 OK", "3,4"),
+                    "/full?page=3" => ("200 OK", "5"),
+                    "/fail?page=1" => ("200 OK", "1,2"),
+                    "/fail?page=2" => ("500 Internal Server Error", ""),
+                    p if p.starts_with("/same?") => ("200 OK", "1,2"),
+                    _ => ("200 OK", ""),
+                };
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        Ok(format!("http://{}", addr))
+    }
+
+    fn request_page(base_url: &str, path: &str, page: usize) -> Request {
+        let url = Url::parse(&format!("{}/{}?page={}", base_url, path, page)).unwrap();
+        Request::new(Method::GET, url)
+    }
+
+    #[tokio::test]
+    async fn exec_by_pages_works() -> anyhow::Result<()> {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let base_url = mock_server(hits.clone()).await?;
+
+        // 第3页不足 page_size 条即停止，不再请求第4页
+        let df =
+            HttpClient::exec_by_pages(&PageFormat, 2, |page| request_page(&base_url, "full", page))
+                .await?;
+
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert_eq!(df.schema(), PageFormat.col_schema().unwrap());
+        let values: Vec<Option<i64>> = df.column("值")?.i64()?.into_iter().collect();
+        assert_eq!(values, vec![Some(1), Some(2), Some(3), Some(4), Some(5)]);
+
+        // 各页已缓存，再次获取不发起请求
+        let df =
+            HttpClient::exec_by_pages(&PageFormat, 2, |page| request_page(&base_url, "full", page))
+                .await?;
+
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert_eq!(df.height(), 5);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exec_by_pages_empty_works() -> anyhow::Result<()> {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let base_url = mock_server(hits.clone()).await?;
+
+        // 首页无数据时返回带列名的空表
+        let df = HttpClient::exec_by_pages(&PageFormat, 2, |page| {
+            request_page(&base_url, "empty", page)
+        })
+        .await?;
+
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(df.schema(), PageFormat.col_schema().unwrap());
+        assert_eq!(df.height(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exec_by_pages_error_works() -> anyhow::Result<()> {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let base_url = mock_server(hits.clone()).await?;
+
+        // 中间页失败时返回错误，而不是截断后的结果
+        let result =
+            HttpClient::exec_by_pages(&PageFormat, 2, |page| request_page(&base_url, "fail", page))
+                .await;
+
+        assert!(result.is_err());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exec_by_pages_repeated_works() -> anyhow::Result<()> {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let base_url = mock_server(hits.clone()).await?;
+
+        // 接口忽略页码时第2页与第1页相同，停止翻页且不重复合并
+        let df =
+            HttpClient::exec_by_pages(&PageFormat, 2, |page| request_page(&base_url, "same", page))
+                .await?;
+
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        let values: Vec<Option<i64>> = df.column("值")?.i64()?.into_iter().collect();
+        assert_eq!(values, vec![Some(1), Some(2)]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exec_by_settled_cache_works() -> anyhow::Result<()> {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let base_url = mock_server(hits.clone()).await?;
+
+        // 无数据同样缓存，再次获取不发起请求，加载的空表列与 schema 一致
+        for _ in 0..2 {
+            let df =
+                HttpClient::exec_by_settled_cache(request_page(&base_url, "empty", 1), PageFormat)
+                    .await?
+                    .data
+                    .unwrap();
+
+            assert_eq!(hits.load(Ordering::SeqCst), 1);
+            assert_eq!(df.schema(), PageFormat.col_schema().unwrap());
+            assert_eq!(df.height(), 0);
+        }
+
+        // 请求失败时返回错误且不缓存
+        for hit in [2, 3] {
+            let result =
+                HttpClient::exec_by_settled_cache(request_page(&base_url, "fail", 2), PageFormat)
+                    .await;
+
+            assert!(result.is_err());
+            assert_eq!(hits.load(Ordering::SeqCst), hit);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn settled_until_works() {
+        let today = Local::now().date_naive();
+        assert_eq!(DateUtils::settled_until(1), today - Duration::days(1));
+        assert!(DateUtils::settled_until(15) < today - Duration::days(1));
+    }
+
+    #[test]
+    fn cache_periods_works() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // 整月已定稿的月份按整月永久缓存，其余部分合并为一段按当日缓存
+        let periods =
+            DateUtils::cache_periods(date(2023, 9, 15), date(2023, 11, 20), date(2023, 11, 5));
+        assert_eq!(
+            periods,
+            vec![
+                (date(2023, 9, 1), date(2023, 9, 30), true),
+                (date(2023, 10, 1), date(2023, 10, 31), true),
+                (date(2023, 11, 1), date(2023, 11, 20), false),
+            ]
+        );
+
+        // 定稿日推移后，已缓存月份的 key 不变
+        let periods =
+            DateUtils::cache_periods(date(2023, 9, 15), date(2023, 11, 20), date(2023, 11, 6));
+        assert_eq!(periods[0], (date(2023, 9, 1), date(2023, 9, 30), true));
+
+        // 月末即定稿日时整月永久缓存
+        let periods =
+            DateUtils::cache_periods(date(2023, 2, 10), date(2023, 2, 20), date(2023, 2, 28));
+        assert_eq!(periods, vec![(date(2023, 2, 1), date(2023, 2, 28), true)]);
+
+        // 均未定稿
+        let periods =
+            DateUtils::cache_periods(date(2023, 10, 30), date(2023, 10, 31), date(2023, 10, 15));
+        assert_eq!(
+            periods,
+            vec![(date(2023, 10, 30), date(2023, 10, 31), false)]
+        );
+    }
 }
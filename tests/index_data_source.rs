@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod index_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::index::IndexConstituentDataSource, DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = IndexConstituentDataSource {
+            symbol: "sh000300".to_string(),
+            date: None,
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("index constituents is: {:?}", df);
+
+        // 沪深300最近一期恰为300只成分股，权重合计约100%
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert_eq!(df.height(), 300);
+        let index_codes = df.column("指数代码")?.utf8()?.clone();
+        assert!(index_codes.into_iter().all(|c| c == Some("000300")));
+        let weight = df.column("权重")?.sum::<f64>().unwrap();
+        assert!((weight - 100.0).abs() < 1.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = IndexConstituentDataSource {
+            symbol: "sh000905".to_string(),
+            date: None,
+        };
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily(
+                "sh",
+                "000905",
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        // 中证500每期500只成分股，半年内含多期结果
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 500);
+        let dates = df.column("生效日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-01-01"..="2023-06-30").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = "{\"result\":{\"pages\":1,\"data\":[{\"INDEX_CODE\":\"000300\",\"SECURITY_CODE\":\"600519\",\"SECURITY_NAME_ABBR\":\"贵州茅台\",\"WEIGHT\":5.62,\"TRADE_DATE\":\"2023-09-28 00:00:00\"},{\"INDEX_CODE\":\"000300\",\"SECURITY_CODE\":\"300750\",\"SECURITY_NAME_ABBR\":\"宁德时代\",\"WEIGHT\":3,\"TRADE_DATE\":\"2023-09-28 00:00:00\"}],\"count\":2},\"success\":true}";
+        let data_source = IndexConstituentDataSource {
+            symbol: "sh000300".to_string(),
+            date: None,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 6));
+        assert_eq!(df.column("symbol")?.utf8()?.get(0), Some("sh000300"));
+        assert_eq!(df.column("代码")?.utf8()?.get(1), Some("300750"));
+        assert_eq!(df.column("权重")?.f64()?.get(1), Some(3.0));
+        assert_eq!(df.column("生效日期")?.utf8()?.get(0), Some("2023-09-28"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod ipo_data_source_works {
+    use polars::{
+        export::chrono::{Local, NaiveDate},
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::ipo::EastmoneyIpoDataSource, DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyIpoDataSource {};
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("ipo is: {:?}", df);
+
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0);
+
+        // 近期可能没有待申购新股，仅检查申购日期不早于今日
+        let df = data_source.upcoming().await?.data.unwrap();
+        let today = Local::now().format("%Y-%m-%d").to_string();
+
+        assert_eq!(df.schema(), schema);
+        let dates = df.column("申购日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| d >= today.as_str())));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyIpoDataSource {};
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily(
+                "",
+                "",
+                NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 10, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0);
+        let dates = df.column("申购日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-10-01"..="2023-10-31").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"SECURITY_CODE":"301292","SECURITY_NAME":"海科新源","APPLY_CODE":"301292","TRADE_MARKET":"深圳证券交易所","APPLY_DATE":"2023-07-06 00:00:00","BALLOT_NUM_DATE":"2023-07-10 00:00:00","BALLOT_PAY_DATE":"2023-07-10 00:00:00","LISTING_DATE":"2023-07-20 00:00:00","ISSUE_PRICE":19.99,"AFTER_ISSUE_PE":45.33,"INDUSTRY_PE_NEW":26.12,"ISSUE_NUM":5600,"ONLINE_ISSUE_NUM":1484,"ONLINE_APPLY_UPPER":14000,"TOP_APPLY_MARKETCAP":14,"ONLINE_ISSUE_LWR":0.0247,"INITIAL_MULTIPLE":3876.55,"CLOSE_PRICE":30.31,"LD_CLOSE_CHANGE":51.63,"PER_SHARES_INCOME":5160},{"SECURITY_CODE":"688702","SECURITY_NAME":"盛科通信","APPLY_CODE":"787702","TRADE_MARKET":"上海证券交易所","APPLY_DATE":"2023-09-05 00:00:00","BALLOT_NUM_DATE":"2023-09-07 00:00:00","BALLOT_PAY_DATE":"2023-09-07 00:00:00","LISTING_DATE":null,"ISSUE_PRICE":42.66,"AFTER_ISSUE_PE":null,"INDUSTRY_PE_NEW":31.05,"ISSUE_NUM":10000,"ONLINE_ISSUE_NUM":2320,"ONLINE_APPLY_UPPER":23000,"TOP_APPLY_MARKETCAP":230,"ONLINE_ISSUE_LWR":0.0415,"INITIAL_MULTIPLE":null,"CLOSE_PRICE":null,"LD_CLOSE_CHANGE":null,"PER_SHARES_INCOME":null}],"count":2},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyIpoDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 21));
+        assert_eq!(df.column("申购日期")?.utf8()?.get(0), Some("2023-07-06"));
+        assert_eq!(df.column("发行价")?.f64()?.get(0), Some(19.99));
+        assert_eq!(df.column("中签率")?.f64()?.get(0), Some(0.0247));
+        assert_eq!(df.column("首日涨幅")?.f64()?.get(0), Some(51.63));
+        // 未上市新股首日数据为空
+        assert_eq!(df.column("上市日期")?.utf8()?.get(1), None);
+        assert_eq!(df.column("首日收盘价")?.f64()?.get(1), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod limit_pool_data_source_works {
+    use polars::{
+        export::chrono::{Duration, Local, NaiveDate},
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::limit_pool::{EastmoneyLimitPoolDataSource, LimitPool},
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyLimitPoolDataSource {
+            pool: LimitPool::LimitUp,
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("limit up pool is: {:?}", df);
+
+        // 非交易日或开盘前股池为空
+        if df.height() > 0 {
+            assert_eq!(df.schema(), data_source.col_schema().unwrap());
+            let today = Local::now().format("%Y-%m-%d").to_string();
+            let dates = df.column("日期")?.utf8()?.clone();
+            assert!(dates.into_iter().all(|d| d == Some(today.as_str())));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        // 接口仅保留最近约30个交易日
+        let end = Local::now().date_naive();
+        let start = end - Duration::days(14);
+        let data_source = EastmoneyLimitPoolDataSource {
+            pool: LimitPool::BrokenLimit,
+        };
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily("", "", start, end)
+            .await?
+            .data
+            .unwrap();
+
+        // 两周内至少有一个交易日(含长假)，按日期倒序
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0);
+        let (start, end) = (start.to_string(), end.to_string());
+        let dates: Vec<&str> = df.column("日期")?.utf8()?.into_iter().flatten().collect();
+        assert_eq!(dates.len(), df.height());
+        assert!(dates
+            .iter()
+            .all(|d| (start.as_str()..=end.as_str()).contains(d)));
+        assert!(dates.windows(2).all(|w| w[0] >= w[1]));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_retention_works() -> anyhow::Result<()> {
+        // 早于保留范围的 start 从范围起始日开始查询
+        let end = Local::now().date_naive() - Duration::days(1);
+        let retention_start =
+            end + Duration::days(1) - Duration::days(EastmoneyLimitPoolDataSource::RETENTION_DAYS);
+        let data_source = EastmoneyLimitPoolDataSource {
+            pool: LimitPool::LimitUp,
+        };
+        let df = data_source
+            .history_daily("", "", NaiveDate::from_ymd_opt(2000, 1, 4).unwrap(), end)
+            .await?
+            .data
+            .unwrap();
+
+        assert!(df.height() > 0);
+        let retention_start = retention_start.to_string();
+        let dates = df.column("日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| d >= retention_start.as_str())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"rc":0,"data":{"tc":2,"qdate":20231031,"pool":[{"c":"600666","m":1,"n":"ST瑞德","p":2180,"zdp":5.31,"amount":150000000,"ltsz":4300000000,"tshare":4500000000,"hs":3.4,"lbc":3,"fbt":93000,"lbt":142501,"fund":25600000,"zbc":1,"hybk":"医药商业","zttj":{"days":5,"ct":3}},{"c":"002829","m":0,"n":"星网宇达","p":24560,"zdp":10.0,"amount":820000000,"ltsz":3900000000,"tshare":4800000000,"hs":21.5,"lbc":1,"fbt":101512,"lbt":101512,"fund":98000000,"zbc":0,"hybk":"国防军工","zttj":{"days":1,"ct":1}}]}}"#;
+        let data_source = EastmoneyLimitPoolDataSource {
+            pool: LimitPool::LimitUp,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 19));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-31"));
+        assert_eq!(df.column("最新价")?.f64()?.get(1), Some(24.56));
+        assert_eq!(df.column("首次封板时间")?.utf8()?.get(0), Some("09:30:00"));
+        assert_eq!(df.column("最后封板时间")?.utf8()?.get(0), Some("14:25:01"));
+        assert_eq!(df.column("连板数")?.i64()?.get(0), Some(3));
+        assert_eq!(df.column("封板资金")?.f64()?.get(1), Some(98000000.0));
+        assert_eq!(df.column("涨停统计")?.utf8()?.get(0), Some("5天3板"));
+
+        // 跌停股池: 连板数为连续跌停天数，炸板次数为开板次数
+        let body = r#"{"rc":0,"data":{"tc":1,"qdate":20231031,"pool":[{"c":"300148","m":0,"n":"天舟文化","p":3120,"zdp":-19.98,"amount":356000000,"ltsz":2500000000,"tshare":2600000000,"pe":-12.3,"fba":120000000,"fund":65000000,"lbt":145630,"days":2,"oc":0,"hybk":"文化传媒"}]}}"#;
+        let data_source = EastmoneyLimitPoolDataSource {
+            pool: LimitPool::LimitDown,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 19));
+        assert_eq!(df.column("连板数")?.i64()?.get(0), Some(2));
+        assert_eq!(df.column("炸板次数")?.i64()?.get(0), Some(0));
+        assert_eq!(df.column("板上成交额")?.f64()?.get(0), Some(120000000.0));
+        assert_eq!(df.column("首次封板时间")?.utf8()?.get(0), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod macro_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::macro_economy::china::{MacroDataSource, MacroIndicator, ShiborTerm},
+        DataResultFormat, HistoryData, HttpSource, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = MacroDataSource {
+            indicator: MacroIndicator::Cpi,
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("cpi is: {:?}", df);
+
+        // 按日期倒序，CPI 为月度数据，日期统一为当月首日
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert!(df.height() > 12);
+        let dates: Vec<&str> = df.column("日期")?.utf8()?.into_iter().flatten().collect();
+        assert_eq!(dates.len(), df.height());
+        assert!(dates.iter().all(|d| d.len() == 10 && d.ends_with("-01")));
+        assert!(dates.windows(2).all(|w| w[0] >= w[1]));
+
+        let data_source = MacroDataSource {
+            indicator: MacroIndicator::Shibor(ShiborTerm::Overnight),
+        };
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily(
+                "",
+                "",
+                NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 10, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 10);
+        let dates = df.column("日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-10-01"..="2023-10-31").contains(&d))));
+        let rates = df.column("数值")?.f64()?.clone();
+        assert!(rates.into_iter().all(|r| r.is_some_and(|r| r > 0.0)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富及商务部接口，需联网"]
+    async fn history_daily_pages_works() -> anyhow::Result<()> {
+        // SHIBOR 自 2006-10 起按日发布，全部历史超过单页条数
+        let data_source = MacroDataSource {
+            indicator: MacroIndicator::Shibor(ShiborTerm::Overnight),
+        };
+        let df = data_source
+            .history_daily(
+                "",
+                "",
+                NaiveDate::from_ymd_opt(2006, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        let dates: Vec<&str> = df.column("日期")?.utf8()?.into_iter().flatten().collect();
+        assert!(dates.len() > 4000);
+        assert_eq!(dates.last().map(|d| &d[..7]), Some("2006-10"));
+        assert!(dates.windows(2).all(|w| w[0] > w[1]));
+
+        let data_source = MacroDataSource {
+            indicator: MacroIndicator::SocialFinancing,
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert!(df.height() > 12);
+        let values = df.column("数值")?.f64()?.clone();
+        assert!(values.into_iter().all(|v| v.is_some()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn request_works() {
+        let data_source = MacroDataSource {
+            indicator: MacroIndicator::Shibor(ShiborTerm::Month3),
+        };
+        let url = data_source.request().url().to_string();
+        assert!(url.contains("RPT_IMP_INTRESTRATEN"));
+        assert!(url.contains("%22203%22"));
+        assert!(url.contains("pageNumber=1&"));
+
+        let data_source = MacroDataSource {
+            indicator: MacroIndicator::SocialFinancing,
+        };
+        let request = data_source.request();
+        assert_eq!(request.method().as_str(), "POST");
+        assert_eq!(request.headers()["content-length"], "0");
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"REPORT_DATE":"2023-07-01 00:00:00","TIME":"2023年第1-3季度","DOMESTICL_PRODUCT_BASE":913027.0,"SUM_SAME":5.2},{"REPORT_DATE":"2023-04-01 00:00:00","TIME":"2023年第1-2季度","DOMESTICL_PRODUCT_BASE":593034.0,"SUM_SAME":5.5}],"count":2},"success":true,"message":"ok","code":0}"#;
+        let data_source = MacroDataSource {
+            indicator: MacroIndicator::Gdp,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.get_column_names(), ["日期", "数值", "统计期"]);
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-07-01"));
+        assert_eq!(df.column("数值")?.f64()?.get(0), Some(5.2));
+        assert_eq!(df.column("统计期")?.utf8()?.get(0), Some("2023Q3"));
+
+        // 2019-08 前无5年期LPR
+        let body = r#"{"result":{"pages":1,"data":[{"TRADE_DATE":"2023-10-20 00:00:00","LPR1Y":3.45,"LPR5Y":4.2},{"TRADE_DATE":"2019-07-22 00:00:00","LPR1Y":4.31,"LPR5Y":null}],"count":2},"success":true,"message":"ok","code":0}"#;
+        let data_source = MacroDataSource {
+            indicator: MacroIndicator::Lpr5Y,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        // 空值行保留至合并全部分页后再去掉，避免整页被误判为最后一页
+        assert_eq!(df.height(), 2);
+        assert_eq!(df.column("统计期")?.utf8()?.get(0), Some("2023-10-20"));
+        assert_eq!(df.column("数值")?.f64()?.get(1), None);
+
+        let body = r#"[{"date":"201502","tiosfs":"13609","rmblone":"10950"},{"date":"201501","tiosfs":"20516","rmblone":"14708"}]"#;
+        let data_source = MacroDataSource {
+            indicator: MacroIndicator::SocialFinancing,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.height(), 2);
+        assert_eq!(df.column("日期")?.utf8()?.get(1), Some("2015-01-01"));
+        assert_eq!(df.column("数值")?.f64()?.get(1), Some(20516.0));
+        assert_eq!(df.column("统计期")?.utf8()?.get(1), Some("2015-01"));
+
+        Ok(())
+    }
+}
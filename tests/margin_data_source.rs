@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod margin_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::margin::{
+            EastmoneyMarginDetailDataSource, ExchangeMarginDataSource, MarginExchange,
+        },
+        DataResultFormat, HistoryData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖上交所及东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        // 2023-10-30、2023-10-31 均为交易日
+        let start = NaiveDate::from_ymd_opt(2023, 10, 30).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+
+        let data_source = ExchangeMarginDataSource {
+            exchange: MarginExchange::Sse,
+        };
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily("", "", start, end)
+            .await?
+            .data
+            .unwrap();
+        tracing::debug!("sse margin is: {:?}", df);
+
+        assert_eq!(df.schema(), schema);
+        assert_eq!(df.height(), 2);
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-31"));
+        assert_eq!(df.column("日期")?.utf8()?.get(1), Some("2023-10-30"));
+        assert!(df
+            .column("融资余额")?
+            .f64()?
+            .get(0)
+            .is_some_and(|v| v > 0.0));
+
+        let data_source = EastmoneyMarginDetailDataSource {};
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily("", "600519", start, end)
+            .await?
+            .data
+            .unwrap();
+
+        assert_eq!(df.schema(), schema);
+        assert_eq!(df.height(), 2);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c == Some("600519")));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-31"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"actionErrors":[],"result":[{"opDate":"20231031","rzye":816567431234,"rzmre":31234567890,"rqyl":4567890123,"rqylje":52345678901,"rqmcl":234567890,"rzrqjyzl":868913110135}],"pageHelp":{"pageNo":1,"pageSize":5000,"total":1}}"#;
+        let data_source = ExchangeMarginDataSource {
+            exchange: MarginExchange::Sse,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 7));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-31"));
+        assert_eq!(df.column("融资余额")?.f64()?.get(0), Some(816567431234.0));
+        assert_eq!(df.column("融券余额")?.f64()?.get(0), Some(52345678901.0));
+
+        let body = r#"[{"metadata":{"tabkey":"tab1"},"data":[{"jrrzmr":"361.24","jrrzye":"7,355.50","jrrjmc":"0.42","jrrjyl":"31.60","jrrjye":"443.63","jrrzrjye":"7,799.50"}]}]"#;
+        let data_source = ExchangeMarginDataSource {
+            exchange: MarginExchange::Szse,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        // 深交所单位为亿元，统一为元
+        assert_eq!(df.shape(), (1, 7));
+        assert_eq!(df.column("融资余额")?.f64()?.get(0), Some(735550000000.0));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn detail_format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"DATE":"2023-10-31 00:00:00","SCODE":"600519","SECNAME":"贵州茅台","SPJ":1720.0,"ZDF":0.65,"RZYE":17123456789.0,"RZMRE":523456789.0,"RZCHE":498765432.0,"RZJME":24691357.0,"RQYE":812345678.0,"RQYL":472300,"RQMCL":12000,"RQCHL":8900,"RQJMG":3100,"RZRQYE":17935802467.0}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyMarginDetailDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 16));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-31"));
+        assert_eq!(df.column("融资买入额")?.f64()?.get(0), Some(523456789.0));
+        assert_eq!(df.column("融资偿还额")?.f64()?.get(0), Some(498765432.0));
+        assert_eq!(df.column("融券余量")?.f64()?.get(0), Some(472300.0));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod money_flow_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::money_flow::{
+            EastmoneyMoneyFlowHistoryDataSource, EastmoneyMoneyFlowRankDataSource, FlowOrder,
+            MoneyFlowColumns,
+        },
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyMoneyFlowRankDataSource {
+            order: FlowOrder::Main,
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("money flow rank is: {:?}", df);
+
+        // 按主力净流入倒序
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert!(df.height() > 0);
+        let inflows: Vec<f64> = df
+            .column("主力净流入-净额")?
+            .f64()?
+            .into_iter()
+            .flatten()
+            .collect();
+        assert!(inflows.windows(2).all(|w| w[0] >= w[1]));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyMoneyFlowHistoryDataSource {};
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily(
+                "sh",
+                "600519",
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2030, 12, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        // 接口只提供最近约100个交易日
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0 && df.height() <= 120);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c == Some("600519")));
+        let dates = df.column("日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| d >= "2023-01-01")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rank_format_works() -> anyhow::Result<()> {
+        let body = r#"{"rc":0,"data":{"total":2,"diff":[{"f2":1800.0,"f3":1.5,"f12":"600519","f14":"贵州茅台","f62":523456789.0,"f66":423456789.0,"f69":12.3,"f72":100000000.0,"f75":2.9,"f78":-200000000.0,"f81":-5.8,"f84":-323456789.0,"f87":-9.4,"f124":1698307200,"f184":15.2},{"f2":"-","f3":"-","f12":"000001","f14":"平安银行","f62":"-","f66":"-","f69":"-","f72":"-","f75":"-","f78":"-","f81":"-","f84":"-","f87":"-","f124":1698307200,"f184":"-"}]}}"#;
+        let data_source = EastmoneyMoneyFlowRankDataSource {
+            order: FlowOrder::Main,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 16));
+        assert_eq!(df.column("代码")?.utf8()?.get(0), Some("600519"));
+        assert_eq!(
+            df.column("主力净流入-净额")?.f64()?.get(0),
+            Some(523456789.0)
+        );
+        assert_eq!(df.column("主力净流入-净占比")?.f64()?.get(1), None);
+
+        let df = MoneyFlowColumns::english(&df)?;
+        assert_eq!(
+            df.column("main_net_inflow")?.f64()?.get(0),
+            Some(523456789.0)
+        );
+        assert_eq!(df.column("small_net_pct")?.f64()?.get(0), Some(-9.4));
+        assert!(df.column("主力净流入-净额").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn history_format_works() -> anyhow::Result<()> {
+        let body = r#"{"rc":0,"data":{"code":"600519","market":1,"name":"贵州茅台","klines":["2023-10-25,-123456789.0,23456789.0,100000000.0,-23456789.0,-100000000.0,-4.52,0.86,3.66,-0.86,-3.66,1780.00,-0.52,0.00,0.00","2023-10-26,223456789.0,-23456789.0,-200000000.0,23456789.0,200000000.0,8.12,-0.85,-7.27,0.85,7.27,1800.00,1.12,0.00,0.00"]}}"#;
+        let data_source = EastmoneyMoneyFlowHistoryDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 15));
+        assert_eq!(df.column("名称")?.utf8()?.get(0), Some("贵州茅台"));
+        assert_eq!(df.column("日期")?.utf8()?.get(1), Some("2023-10-26"));
+        assert_eq!(
+            df.column("主力净流入-净额")?.f64()?.get(0),
+            Some(-123456789.0)
+        );
+        assert_eq!(
+            df.column("超大单净流入-净额")?.f64()?.get(0),
+            Some(-100000000.0)
+        );
+        assert_eq!(df.column("小单净流入-净占比")?.f64()?.get(1), Some(-0.85));
+        assert_eq!(df.column("收盘价")?.f64()?.get(1), Some(1800.0));
+
+        Ok(())
+    }
+}
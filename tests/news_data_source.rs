@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod news_data_source_works {
+    use polars::{export::chrono::NaiveDate, prelude::TakeRandomUtf8};
+    use qshare::{
+        sina::stock::news::{EastmoneyAnnouncementDataSource, EastmoneyNewsDataSource},
+        DataResultFormat, HistoryData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let start = NaiveDate::from_ymd_opt(2023, 10, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+
+        let data_source = EastmoneyAnnouncementDataSource {};
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily("", "sh600000", start, end)
+            .await?
+            .data
+            .unwrap();
+        tracing::debug!("announcements is: {:?}", df);
+
+        // 浦发银行于 2023-10-30 披露三季报，关联的其他证券被过滤
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c == Some("600000")));
+        let dates = df.column("公告日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-10-01"..="2023-10-31").contains(&d))));
+
+        // 资讯只按发布时间倒序分页，较早的资讯可能已不可查，仅检查时间范围与顺序
+        let data_source = EastmoneyNewsDataSource {};
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily("", "600000", start, end)
+            .await?
+            .data
+            .unwrap();
+
+        assert_eq!(df.schema(), schema);
+        let times: Vec<&str> = df
+            .column("发布时间")?
+            .utf8()?
+            .into_iter()
+            .flatten()
+            .collect();
+        assert!(times
+            .iter()
+            .all(|t| ("2023-10-01".."2023-11-01").contains(t)));
+        assert!(times.windows(2).all(|w| w[0] >= w[1]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn announcement_format_works() -> anyhow::Result<()> {
+        let body = r#"{"data":{"list":[{"art_code":"AN202310301606173393","codes":[{"stock_code":"600000","short_name":"浦发银行","market_code":"1"}],"columns":[{"column_code":"001002003","column_name":"三季度报告全文"}],"notice_date":"2023-10-31 00:00:00","display_time":"2023-10-30 17:43:12:370","title":"浦发银行:2023年第三季度报告"},{"art_code":"AN202310271605000001","codes":[{"stock_code":"600000","short_name":"浦发银行","market_code":"1"},{"stock_code":"110059","short_name":"浦发转债","market_code":"1"}],"columns":[{"column_code":"001001","column_name":"董事会决议公告"},{"column_code":"001002","column_name":"其他"}],"notice_date":"2023-10-28 00:00:00","display_time":"2023-10-27 19:30:01:000","title":"浦发银行:董事会决议公告"}],"page_index":1,"page_size":100,"total_hits":2},"success":1}"#;
+        let data_source = EastmoneyAnnouncementDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        // 关联多只证券的公告按证券展开
+        assert_eq!(df.shape(), (3, 8));
+        assert_eq!(df.column("公告日期")?.utf8()?.get(0), Some("2023-10-31"));
+        assert_eq!(
+            df.column("发布时间")?.utf8()?.get(0),
+            Some("2023-10-30 17:43:12")
+        );
+        assert_eq!(
+            df.column("公告链接")?.utf8()?.get(0),
+            Some("https://pdf.dfcfw.com/pdf/H2_AN202310301606173393_1.pdf")
+        );
+        assert_eq!(
+            df.column("公告类型")?.utf8()?.get(1),
+            Some("董事会决议公告,其他")
+        );
+        assert_eq!(df.column("代码")?.utf8()?.get(2), Some("110059"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn news_format_works() -> anyhow::Result<()> {
+        let body = r#"jQuery({"code":0,"result":{"cmsArticleWebOld":[{"date":"2023-10-31 08:05:20","title":"<em>浦发银行</em>前三季度净利润同比下降","content":"<em>浦发银行</em>10月30日晚间披露三季报","mediaName":"证券时报","url":"http://finance.eastmoney.com/a/202310312887000001.html"}]}})"#;
+        let data_source = EastmoneyNewsDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 5));
+        assert_eq!(
+            df.column("标题")?.utf8()?.get(0),
+            Some("浦发银行前三季度净利润同比下降")
+        );
+        assert_eq!(
+            df.column("发布时间")?.utf8()?.get(0),
+            Some("2023-10-31 08:05:20")
+        );
+        assert_eq!(df.column("来源")?.utf8()?.get(0), Some("证券时报"));
+
+        Ok(())
+    }
+}
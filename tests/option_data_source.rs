@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod option_data_source_works {
+    use polars::{
+        export::chrono::{Local, NaiveDate},
+        prelude::{DataType, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::option::sina::{
+            OptionUnderlying, SinaOptionChainDataSource, SinaOptionHistoryDataSource,
+        },
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[test]
+    fn expiries_works() -> anyhow::Result<()> {
+        let date = NaiveDate::from_ymd_opt(2023, 12, 1).unwrap();
+
+        let df = OptionUnderlying::Etf50.expiries(date)?;
+        assert_eq!(df.shape(), (4, 3));
+        let months = df.column("到期月份")?.utf8()?;
+        assert_eq!(months.get(0), Some("2312"));
+        assert_eq!(months.get(1), Some("2401"));
+        assert_eq!(months.get(2), Some("2403"));
+        assert_eq!(months.get(3), Some("2406"));
+        // 2023年12月第四个星期三
+        assert_eq!(df.column("到期日")?.utf8()?.get(0), Some("2023-12-27"));
+        assert_eq!(df.column("剩余天数")?.i64()?.get(0), Some(26));
+
+        let df = OptionUnderlying::Io.expiries(date)?;
+        assert_eq!(df.shape(), (6, 3));
+        // 2023年12月第三个星期五
+        assert_eq!(df.column("到期日")?.utf8()?.get(0), Some("2023-12-15"));
+        let months = df.column("到期月份")?.utf8()?;
+        assert_eq!(months.get(3), Some("2403"));
+        assert_eq!(months.get(5), Some("2409"));
+
+        // 当月已到期时从下月开始
+        let date = NaiveDate::from_ymd_opt(2023, 12, 28).unwrap();
+        let df = OptionUnderlying::Etf300.expiries(date)?;
+        assert_eq!(df.column("到期月份")?.utf8()?.get(0), Some("2401"));
+
+        assert!(OptionUnderlying::Io.expiry("2313").is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖新浪期权行情接口，需联网"]
+    async fn chain_real_time_data_works() -> anyhow::Result<()> {
+        let expiries = OptionUnderlying::Etf50.expiries(Local::now().date_naive())?;
+        let month = expiries
+            .column("到期月份")?
+            .utf8()?
+            .get(0)
+            .unwrap()
+            .to_string();
+        let expiry = expiries
+            .column("到期日")?
+            .utf8()?
+            .get(0)
+            .unwrap()
+            .to_string();
+
+        let data_source = SinaOptionChainDataSource {
+            underlying: OptionUnderlying::Etf50,
+            month,
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("option chain is: {:?}", df);
+
+        // 行情列之后追加 到期日,隐含波动率，看涨看跌合约成对挂牌
+        let mut names = data_source
+            .col_schema()
+            .unwrap()
+            .iter_names()
+            .cloned()
+            .collect::<Vec<_>>();
+        names.extend(["到期日".into(), "隐含波动率".into()]);
+        assert_eq!(df.get_column_names_owned(), names);
+        let types: Vec<&str> = df.column("类型")?.utf8()?.into_iter().flatten().collect();
+        let calls = types.iter().filter(|t| **t == "C").count();
+        assert!(calls > 0);
+        assert_eq!(calls * 2, types.len());
+        let expiries = df.column("到期日")?.utf8()?.clone();
+        assert!(expiries.into_iter().all(|d| d == Some(expiry.as_str())));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖新浪期权行情接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = SinaOptionHistoryDataSource {};
+        let df = data_source
+            .history_daily(
+                "",
+                "10006001",
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+        tracing::debug!("option history is: {:?}", df);
+
+        assert!(df.height() > 0);
+        assert_eq!(df.get_column_names()[0], "代码");
+        assert_eq!(df.column("收盘")?.dtype(), &DataType::Float64);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c == Some("10006001")));
+        let dates = df.column("日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-01-01"..="2023-12-31").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn etf_chain_format_works() -> anyhow::Result<()> {
+        let quote = |name: &str, strike: &str, last: &str| {
+            let mut values = vec![""; 43];
+            values[0] = "10";
+            values[1] = "0.0500";
+            values[2] = last;
+            values[3] = "0.0520";
+            values[4] = "12";
+            values[5] = "3456";
+            values[7] = strike;
+            values[37] = name;
+            values[41] = "789";
+            values.join(",")
+        };
+        let body = format!(
+            "var hq_str_CON_OP_10006001=\"{}\";\nvar hq_str_CON_OP_10006010=\"{}\";\nvar hq_str_CON_OP_10006099=\"\";",
+            quote("50ETF购12月2400", "2.4000", "0.0510"),
+            quote("50ETF沽12月2400", "2.4000", "0.0320")
+        );
+        let data_source = SinaOptionChainDataSource {
+            underlying: OptionUnderlying::Etf50,
+            month: "2312".to_string(),
+        };
+
+        let data_result = data_source.to_dataframe(Some(body))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 11));
+        assert_eq!(df.column("symbol")?.utf8()?.get(0), Some("10006001"));
+        assert_eq!(df.column("类型")?.utf8()?.get(0), Some("C"));
+        assert_eq!(df.column("类型")?.utf8()?.get(1), Some("P"));
+        assert_eq!(df.column("行权价")?.f64()?.get(1), Some(2.4));
+        assert_eq!(df.column("最新价")?.f64()?.get(1), Some(0.032));
+        assert_eq!(df.column("成交量")?.f64()?.get(0), Some(789.0));
+        assert_eq!(df.column("持仓量")?.f64()?.get(0), Some(3456.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn io_chain_format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"status":{"code":0},"data":{"up":[["5","120.2","121.0","121.8","3","1500","2.5","3600","io2312C3600"],["2","40.0","40.6","41.2","1","900","-1.2","3700","io2312C3700"]],"down":[["4","20.0","20.4","20.8","6","1800","-3.0","io2312P3600"],["1","58.0","59.0","60.0","2","700","1.0","io2312P3700"]]}}}"#;
+        let data_source = SinaOptionChainDataSource {
+            underlying: OptionUnderlying::Io,
+            month: "2312".to_string(),
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (4, 11));
+        let symbols = df.column("symbol")?.utf8()?;
+        assert_eq!(symbols.get(0), Some("io2312C3600"));
+        assert_eq!(symbols.get(1), Some("io2312P3600"));
+        assert_eq!(df.column("类型")?.utf8()?.get(1), Some("P"));
+        assert_eq!(df.column("行权价")?.f64()?.get(1), Some(3600.0));
+        assert_eq!(df.column("最新价")?.f64()?.get(3), Some(59.0));
+        assert_eq!(df.column("成交量")?.f64()?.get(0), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn history_format_works() -> anyhow::Result<()> {
+        let body = "/*<script>location.href='//sina.com';</script>*/\n([{\"d\":\"2023-12-01\",\"o\":\"0.0500\",\"h\":\"0.0560\",\"l\":\"0.0480\",\"c\":\"0.0510\",\"v\":\"12345\"}]);";
+        let data_source = SinaOptionHistoryDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 6));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-12-01"));
+        assert_eq!(df.column("收盘")?.f64()?.get(0), Some(0.051));
+        assert_eq!(df.column("成交量")?.f64()?.get(0), Some(12345.0));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod option_pricing_works {
+    use polars::{
+        df,
+        export::chrono::NaiveDate,
+        prelude::{NamedFrom, TakeRandom},
+    };
+    use qshare::sina::option::pricing::{OptionPricing, OptionType, PricingModel};
+
+    fn pricing(model: PricingModel) -> OptionPricing {
+        OptionPricing {
+            model,
+            underlying_price: 100.0,
+            rate: 0.05,
+            today: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+        }
+    }
+
+    #[test]
+    fn black_scholes_works() {
+        let bs = pricing(PricingModel::BlackScholes);
+
+        // S=100, K=100, r=5%, sigma=20%, T=1 教科书数值
+        let call = bs.price(OptionType::Call, 100.0, 1.0, 0.2);
+        let put = bs.price(OptionType::Put, 100.0, 1.0, 0.2);
+        assert!((call - 10.4506).abs() < 1e-3);
+        assert!((put - 5.5735).abs() < 1e-3);
+
+        // 看涨看跌平价 C - P = S - K * e^(-rT)
+        assert!((call - put - (100.0 - 100.0 * (-0.05f64).exp())).abs() < 1e-6);
+
+        let greeks = bs.greeks(OptionType::Call, 100.0, 1.0, 0.2);
+        assert!((greeks.delta - 0.6368).abs() < 1e-3);
+        assert!((greeks.gamma - 0.01876).abs() < 1e-4);
+        assert!((greeks.vega - 0.3752).abs() < 1e-3);
+        assert!((greeks.theta - (-6.414 / 365.0)).abs() < 1e-4);
+        assert!((greeks.rho - 0.5323).abs() < 1e-3);
+
+        let put_greeks = bs.greeks(OptionType::Put, 100.0, 1.0, 0.2);
+        assert!((greeks.delta - put_greeks.delta - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn black76_works() {
+        let black = pricing(PricingModel::Black76);
+
+        let call = black.price(OptionType::Call, 95.0, 0.5, 0.3);
+        let put = black.price(OptionType::Put, 95.0, 0.5, 0.3);
+        // 期货期权平价 C - P = (F - K) * e^(-rT)
+        assert!((call - put - 5.0 * (-0.025f64).exp()).abs() < 1e-6);
+
+        let greeks = black.greeks(OptionType::Call, 95.0, 0.5, 0.3);
+        assert!((greeks.rho * 100.0 + 0.5 * call).abs() < 1e-9);
+    }
+
+    #[test]
+    fn implied_vol_works() {
+        for model in [PricingModel::BlackScholes, PricingModel::Black76] {
+            let p = pricing(model);
+            for (option_type, strike) in [(OptionType::Call, 90.0), (OptionType::Put, 110.0)] {
+                let price = p.price(option_type, strike, 0.25, 0.35);
+                let vol = p.implied_vol(option_type, strike, 0.25, price).unwrap();
+                assert!((vol - 0.35).abs() < 1e-6);
+            }
+        }
+
+        // 低于内在价值无解
+        let bs = pricing(PricingModel::BlackScholes);
+        assert_eq!(bs.implied_vol(OptionType::Call, 80.0, 0.25, 1.0), None);
+    }
+
+    #[test]
+    fn with_greeks_works() -> anyhow::Result<()> {
+        let black = OptionPricing {
+            model: PricingModel::Black76,
+            underlying_price: 100.0,
+            rate: 0.0,
+            today: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+        };
+        let t = 73.0 / 365.0;
+        let call = black.price(OptionType::Call, 100.0, t, 0.2);
+        let put = black.price(OptionType::Put, 100.0, t, 0.2);
+
+        let chain = df!(
+            "类型" => ["C", "P", "C"],
+            "行权价" => [100.0, 100.0, 120.0],
+            "最新价" => [Some(call), Some(put), None],
+            "到期日" => ["2023-03-15", "2023-03-15", "2023-03-15"]
+        )?;
+
+        let forward = OptionPricing::parity_forward(&chain)?.unwrap();
+        assert!((forward - 100.0).abs() < 1e-9);
+
+        let df = black.with_greeks(&chain)?;
+        assert_eq!(df.shape(), (3, 11));
+
+        let vols = df.column("隐含波动率")?.f64()?;
+        assert!((vols.get(0).unwrap() - 0.2).abs() < 1e-6);
+        assert!((vols.get(1).unwrap() - 0.2).abs() < 1e-6);
+        assert_eq!(vols.get(2), None);
+
+        let delta = df.column("Delta")?.f64()?;
+        assert!((delta.get(0).unwrap() - delta.get(1).unwrap() - 1.0).abs() < 1e-6);
+
+        // 重复计算时覆盖已有列
+        assert_eq!(black.with_greeks(&df)?.shape(), (3, 11));
+
+        Ok(())
+    }
+}
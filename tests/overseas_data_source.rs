@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod overseas_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{DataType, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::overseas::{
+            EastmoneyAhPremiumDataSource, EastmoneyOverseasSpotDataSource, OverseasMarket,
+        },
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        for (market, currency) in [(OverseasMarket::Hk, "HKD"), (OverseasMarket::Us, "USD")] {
+            let data_source = EastmoneyOverseasSpotDataSource { market };
+            let df = data_source.real_time_data().await?.data.unwrap();
+            tracing::debug!("overseas spot is: {:?}", df);
+
+            assert_eq!(df.schema(), data_source.col_schema().unwrap());
+            assert!(df.height() > 0);
+            let currencies = df.column("币种")?.utf8()?.clone();
+            assert!(currencies.into_iter().all(|c| c == Some(currency)));
+        }
+
+        let data_source = EastmoneyAhPremiumDataSource {};
+        let df = data_source.real_time_data().await?.data.unwrap();
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        let codes = df.column("A股代码")?.utf8()?.clone();
+        assert!(codes.into_iter().any(|c| c == Some("601398")));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        // 港股默认市场 116，美股按实时行情查找代码所在市场
+        for (market, symbol) in [(OverseasMarket::Hk, "00700"), (OverseasMarket::Us, "AAPL")] {
+            let data_source = EastmoneyOverseasSpotDataSource { market };
+            let df = data_source
+                .history_daily(
+                    "",
+                    symbol,
+                    NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                    NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+                )
+                .await?
+                .data
+                .unwrap();
+
+            assert!(df.height() > 100);
+            assert_eq!(df.column("收盘")?.dtype(), &DataType::Float64);
+            let dates = df.column("日期")?.utf8()?.clone();
+            assert!(dates
+                .into_iter()
+                .all(|d| d.is_some_and(|d| ("2023-01-01"..="2023-06-30").contains(&d))));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"rc":0,"data":{"total":2,"diff":[{"f2":189.95,"f3":1.2,"f4":2.25,"f5":52164110,"f6":9876543210.0,"f7":1.5,"f8":0.34,"f9":31.2,"f12":"AAPL","f13":105,"f14":"苹果","f15":190.1,"f16":187.3,"f17":188.0,"f18":187.7,"f20":2950000000000.0,"f23":"-"},{"f2":"-","f3":"-","f4":"-","f5":"-","f6":"-","f7":"-","f8":"-","f9":"-","f12":"BRK_A","f13":106,"f14":"伯克希尔哈撒韦-A","f15":"-","f16":"-","f17":"-","f18":"-","f20":"-","f23":"-"}]}}"#;
+        let data_source = EastmoneyOverseasSpotDataSource {
+            market: OverseasMarket::Us,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 19));
+        assert_eq!(df.column("代码")?.utf8()?.get(0), Some("AAPL"));
+        assert_eq!(df.column("最新价")?.f64()?.get(0), Some(189.95));
+        assert_eq!(df.column("最新价")?.f64()?.get(1), None);
+        assert_eq!(df.column("币种")?.utf8()?.get(1), Some("USD"));
+        assert_eq!(df.column("secid")?.utf8()?.get(1), Some("106.BRK_A"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ah_premium_format_works() -> anyhow::Result<()> {
+        let body = r#"{"rc":0,"data":{"total":1,"diff":[{"f2":5.12,"f3":-0.58,"f12":"00939","f14":"建设银行","f186":6.95,"f187":0.29,"f188":46.82,"f189":1.47,"f191":"601939","f193":"建设银行"}]}}"#;
+        let data_source = EastmoneyAhPremiumDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 10));
+        assert_eq!(df.column("H股代码")?.utf8()?.get(0), Some("00939"));
+        assert_eq!(df.column("A股代码")?.utf8()?.get(0), Some("601939"));
+        assert_eq!(df.column("symbol")?.utf8()?.get(0), Some("601939"));
+        assert_eq!(df.column("溢价率")?.f64()?.get(0), Some(46.82));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod security_data_source_works {
+    use polars::{
+        df,
+        export::chrono::NaiveDate,
+        prelude::{NamedFrom, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::security::{
+            EastmoneyNameChangeDataSource, SecurityList, SecurityListDataSource, SecurityMaster,
+        },
+        DataResultFormat, HttpSource, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖深交所及东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = SecurityListDataSource {
+            list: SecurityList::DelistedSz,
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("delisted is: {:?}", df);
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert!(df.height() > 0);
+        let exchanges = df.column("交易所")?.utf8()?.clone();
+        assert!(exchanges.into_iter().all(|e| e == Some("SZ")));
+        let delist_dates = df.column("退市日期")?.utf8()?.clone();
+        assert!(delist_dates.into_iter().all(|d| d.is_some()));
+
+        // 序号越大越新，最后一条为当前简称
+        let data_source = EastmoneyNameChangeDataSource {
+            symbol: "600519".to_string(),
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        let names = df.column("名称")?.utf8()?.clone();
+        assert_eq!(names.get(names.len() - 1), Some("贵州茅台"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn request_works() {
+        let data_source = SecurityListDataSource {
+            list: SecurityList::DelistedSh,
+        };
+        let request = data_source.request();
+        assert!(request.url().as_str().contains("COMPANY_STATUS=3"));
+        assert!(request.headers().contains_key("referer"));
+
+        let data_source = SecurityListDataSource {
+            list: SecurityList::Listed,
+        };
+        let url = data_source.request().url().to_string();
+        assert!(url.contains("pn=1&pz=100&"));
+        assert!(url.contains("fid=f12"));
+
+        let data_source = EastmoneyNameChangeDataSource {
+            symbol: "bj430047".to_string(),
+        };
+        let url = data_source.request().url().to_string();
+        assert!(url.contains("430047.BJ"));
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"rc":0,"data":{"total":2,"diff":[{"f12":"600519","f14":"贵州茅台","f26":20010827,"f38":1256197800.0,"f39":1256197800.0,"f100":"酿酒行业"},{"f12":"300750","f14":"宁德时代","f26":20180611,"f38":4398807222.0,"f39":3906542620.0,"f100":"电池"}]}}"#;
+        let data_source = SecurityListDataSource {
+            list: SecurityList::Listed,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 10));
+        assert_eq!(df.column("上市日期")?.utf8()?.get(0), Some("2001-08-27"));
+        assert_eq!(df.column("交易所")?.utf8()?.get(0), Some("SH"));
+        assert_eq!(df.column("板块")?.utf8()?.get(1), Some("创业板"));
+        assert_eq!(df.column("退市日期")?.utf8()?.get(0), None);
+        assert_eq!(df.column("流通股本")?.f64()?.get(1), Some(3906542620.0));
+
+        let body = r#"[{"metadata":{"tabkey":"tab1","pagecount":1},"data":[]},{"metadata":{"tabkey":"tab2","pagecount":1},"data":[{"zqdm":"<a href='#'><u>000003</u></a>","zqjc":"PT金田A","ssrq":"1991-07-03","zzrq":"2002-06-14"}]}]"#;
+        let data_source = SecurityListDataSource {
+            list: SecurityList::DelistedSz,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 10));
+        assert_eq!(df.column("代码")?.utf8()?.get(0), Some("000003"));
+        assert_eq!(df.column("退市日期")?.utf8()?.get(0), Some("2002-06-14"));
+        assert_eq!(df.column("总股本")?.f64()?.get(0), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn name_change_format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"SECUCODE":"600519.SH","SECURITY_CODE":"600519","SECURITY_NAME_ABBR":"贵州茅台","FORMERNAME":"贵州茅台→G茅台"}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyNameChangeDataSource {
+            symbol: "600519".to_string(),
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (3, 4));
+        assert_eq!(df.column("名称")?.utf8()?.get(1), Some("G茅台"));
+        assert_eq!(df.column("名称")?.utf8()?.get(2), Some("贵州茅台"));
+        assert_eq!(df.column("序号")?.i64()?.get(2), Some(3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn universe_works() -> anyhow::Result<()> {
+        let data = df!(
+            "代码" => ["000003", "600519", "000003"],
+            "名称" => ["PT金田A", "贵州茅台", "PT金田A"],
+            "上市日期" => ["1991-07-03", "2001-08-27", "1991-07-03"],
+            "退市日期" => [Some("2002-06-14"), None, None]
+        )?;
+        let master = SecurityMaster::from(data);
+
+        // 按代码去重，保留先出现的退市记录
+        assert_eq!(master.data.height(), 2);
+        assert_eq!(master.get("sz000003")?.height(), 1);
+
+        let universe = master.universe(NaiveDate::from_ymd_opt(2000, 1, 4).unwrap())?;
+        assert_eq!(universe.height(), 1);
+        assert_eq!(universe.column("代码")?.utf8()?.get(0), Some("000003"));
+
+        let universe = master.universe(NaiveDate::from_ymd_opt(2010, 1, 4).unwrap())?;
+        assert_eq!(universe.height(), 1);
+        assert_eq!(universe.column("代码")?.utf8()?.get(0), Some("600519"));
+
+        assert_eq!(SecurityMaster::board("688981"), "科创板");
+        assert_eq!(SecurityMaster::exchange("920002"), "BJ");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod shareholder_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::stock::shareholder::{EastmoneyShareholderDataSource, Shareholder},
+        DataResultFormat, HistoryData, RealTimeData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn real_time_data_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyShareholderDataSource {
+            holder: Shareholder::TopFloatHolders,
+            symbol: Some("600519".to_string()),
+            report_date: Some(NaiveDate::from_ymd_opt(2023, 9, 30).unwrap()),
+        };
+        let df = data_source.real_time_data().await?.data.unwrap();
+        tracing::debug!("top float holders is: {:?}", df);
+
+        assert_eq!(df.schema(), data_source.col_schema().unwrap());
+        assert_eq!(df.height(), 10);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c == Some("600519")));
+        let dates = df.column("报告期")?.utf8()?.clone();
+        assert!(dates.into_iter().all(|d| d == Some("2023-09-30")));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "依赖东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let data_source = EastmoneyShareholderDataSource {
+            holder: Shareholder::HolderCount,
+            symbol: None,
+            report_date: None,
+        };
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily(
+                "",
+                "600519",
+                NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            )
+            .await?
+            .data
+            .unwrap();
+
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 0);
+        let codes = df.column("代码")?.utf8()?.clone();
+        assert!(codes.into_iter().all(|c| c == Some("600519")));
+        let dates = df.column("报告期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2022-01-01"..="2023-12-31").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"{"result":{"pages":1,"data":[{"SECUCODE":"600519.SH","SECURITY_CODE":"600519","END_DATE":"2023-09-30 00:00:00","HOLDER_RANK":1,"HOLDER_NAME":"中国贵州茅台酒厂(集团)有限责任公司","HOLDER_NEWTYPE":"其它","SHARES_TYPE":"流通A股","HOLD_NUM":678291955,"FREE_HOLDNUM_RATIO":54.0,"HOLD_NUM_CHANGE":"不变","CHANGE_RATIO":null},{"SECUCODE":"600519.SH","SECURITY_CODE":"600519","END_DATE":"2023-09-30 00:00:00","HOLDER_RANK":2,"HOLDER_NAME":"香港中央结算有限公司","HOLDER_NEWTYPE":"其它","SHARES_TYPE":"流通A股","HOLD_NUM":85741437,"FREE_HOLDNUM_RATIO":6.83,"HOLD_NUM_CHANGE":"-3,123,456","CHANGE_RATIO":-3.51}],"count":2},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyShareholderDataSource {
+            holder: Shareholder::TopFloatHolders,
+            symbol: Some("600519".to_string()),
+            report_date: None,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 11));
+        assert_eq!(df.column("报告期")?.utf8()?.get(0), Some("2023-09-30"));
+        assert_eq!(df.column("名次")?.i64()?.get(1), Some(2));
+        assert_eq!(df.column("占流通股比例")?.f64()?.get(1), Some(6.83));
+        assert_eq!(df.column("增减")?.utf8()?.get(0), Some("不变"));
+
+        let body = r#"{"result":{"pages":1,"data":[{"SECURITY_CODE":"600519","SECURITY_NAME_ABBR":"贵州茅台","END_DATE":"2023-09-30 00:00:00","PRE_END_DATE":"2023-06-30 00:00:00","HOLD_NOTICE_DATE":"2023-10-21 00:00:00","HOLDER_NUM":178621,"PRE_HOLDER_NUM":165327,"HOLDER_NUM_CHANGE":13294,"HOLDER_NUM_RATIO":8.04,"INTERVAL_CHRATE":-5.26,"AVG_HOLD_NUM":7029.5,"AVG_MARKET_CAP":12525384.6,"TOTAL_MARKET_CAP":2237282000000,"TOTAL_A_SHARES":1256197800,"CHANGE_REASON":null}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyShareholderDataSource {
+            holder: Shareholder::HolderCount,
+            symbol: Some("600519".to_string()),
+            report_date: None,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 16));
+        assert_eq!(df.column("公告日期")?.utf8()?.get(0), Some("2023-10-21"));
+        assert_eq!(df.column("股东户数")?.f64()?.get(0), Some(178621.0));
+        assert_eq!(df.column("户数增减比例")?.f64()?.get(0), Some(8.04));
+
+        let body = r#"{"result":{"pages":1,"data":[{"SECURITY_CODE":"600519","SECURITY_NAME_ABBR":"贵州茅台","REPORT_DATE":"2023-09-30 00:00:00","HOLDER_CODE":"005827","HOLDER_NAME":"易方达蓝筹精选混合","TOTAL_SHARES":1850000,"HOLD_MARKET_CAP":3303000000,"TOTALSHARES_RATIO":0.15,"FREESHARES_RATIO":0.15}],"count":1},"success":true,"message":"ok","code":0}"#;
+        let data_source = EastmoneyShareholderDataSource {
+            holder: Shareholder::FundHoldings,
+            symbol: Some("600519".to_string()),
+            report_date: None,
+        };
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (1, 10));
+        assert_eq!(df.column("基金代码")?.utf8()?.get(0), Some("005827"));
+        assert_eq!(df.column("持股市值")?.f64()?.get(0), Some(3303000000.0));
+
+        Ok(())
+    }
+}
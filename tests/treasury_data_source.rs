@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod treasury_data_source_works {
+    use polars::{
+        export::chrono::NaiveDate,
+        prelude::{DataType, TakeRandom, TakeRandomUtf8},
+    };
+    use qshare::{
+        sina::bond::treasury::{
+            BondIndex, ChinaBondYieldCurveDataSource, EastmoneyBondIndexDataSource,
+        },
+        DataResultFormat, HistoryData,
+    };
+
+    #[tokio::test]
+    #[ignore = "依赖中国债券信息网及东方财富接口，需联网"]
+    async fn history_daily_works() -> anyhow::Result<()> {
+        let start = NaiveDate::from_ymd_opt(2023, 10, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+
+        let data_source = ChinaBondYieldCurveDataSource {};
+        let schema = data_source.col_schema().unwrap();
+        let df = data_source
+            .history_daily("", "", start, end)
+            .await?
+            .data
+            .unwrap();
+        tracing::debug!("yield curve is: {:?}", df);
+
+        // 按日期倒序，10年期国债收益率在合理区间
+        assert_eq!(df.schema(), schema);
+        assert!(df.height() > 10);
+        let dates: Vec<&str> = df.column("日期")?.utf8()?.into_iter().flatten().collect();
+        assert_eq!(dates.len(), df.height());
+        assert!(dates
+            .iter()
+            .all(|d| ("2023-10-01"..="2023-10-31").contains(d)));
+        assert!(dates.windows(2).all(|w| w[0] > w[1]));
+        let yields = df.column("10年")?.f64()?.clone();
+        assert!(yields
+            .into_iter()
+            .all(|y| y.is_some_and(|y| y > 1.0 && y < 5.0)));
+
+        let data_source = EastmoneyBondIndexDataSource {
+            index: BondIndex::Treasury,
+        };
+        let df = data_source
+            .history_daily("", "", start, end)
+            .await?
+            .data
+            .unwrap();
+
+        assert!(df.height() > 10);
+        assert_eq!(df.column("收盘")?.dtype(), &DataType::Float64);
+        let dates = df.column("日期")?.utf8()?.clone();
+        assert!(dates
+            .into_iter()
+            .all(|d| d.is_some_and(|d| ("2023-10-01"..="2023-10-31").contains(&d))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_works() -> anyhow::Result<()> {
+        let body = r#"<table class="tablelist"><tr><td>曲线名称</td><td>日期</td><td>3月</td><td>6月</td><td>1年</td><td>3年</td><td>5年</td><td>7年</td><td>10年</td><td>30年</td></tr>
+<tr><td>中债国债收益率曲线</td><td>2023-10-31</td><td>2.1825</td><td>2.2341</td><td>2.2615</td><td>2.4328</td><td>2.5418</td><td>2.6793</td><td>2.6870</td><td>2.9800</td></tr>
+<tr><td>中债中短期票据收益率曲线(AAA)</td><td>2023-10-31</td><td>2.5401</td><td>2.5800</td><td>2.6300</td><td>2.8200</td><td>2.9600</td><td>3.0800</td><td>3.1700</td><td>&nbsp;</td></tr>
+<tr><td>中债国债收益率曲线</td><td>2023-10-30</td><td>2.1700</td><td>2.2200</td><td>2.2500</td><td>2.4200</td><td>2.5300</td><td>2.6700</td><td>2.6800</td><td>2.9700</td></tr></table>"#;
+        let data_source = ChinaBondYieldCurveDataSource {};
+
+        let data_result = data_source.to_dataframe(Some(body.to_string()))?;
+        let df = data_source.format(data_result.data).data.unwrap();
+
+        assert_eq!(df.shape(), (2, 9));
+        assert_eq!(df.column("日期")?.utf8()?.get(0), Some("2023-10-31"));
+        assert_eq!(df.column("3月")?.f64()?.get(0), Some(2.1825));
+        assert_eq!(df.column("30年")?.f64()?.get(1), Some(2.97));
+
+        let spread = ChinaBondYieldCurveDataSource::term_spread(&df, "10年", "1年")?;
+        let bp = spread.column("期限利差")?.f64()?.get(0).unwrap();
+        assert!((bp - 42.55).abs() < 1e-9);
+
+        Ok(())
+    }
+}